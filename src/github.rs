@@ -72,6 +72,8 @@ pub struct User {
 
 #[derive(Deserialize, Debug)]
 pub struct DiscussionCategory {
+    pub id: u64,
+    pub node_id: String,
     pub name: String,
     pub description: String,
     pub emoji: Option<String>,
@@ -81,8 +83,25 @@ pub struct DiscussionCategory {
     pub is_answerable: bool,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct Reactions {
+    #[serde(rename = "+1")]
+    pub plus_one: u32,
+    #[serde(rename = "-1")]
+    pub minus_one: u32,
+    pub laugh: u32,
+    pub hooray: u32,
+    pub confused: u32,
+    pub heart: u32,
+    pub rocket: u32,
+    pub eyes: u32,
+    pub total_count: u32,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Comment {
+    pub id: u64,
+    pub node_id: String,
     pub html_url: String,
     pub user: User,
     pub position: Option<u32>,
@@ -91,14 +110,17 @@ pub struct Comment {
     pub updated_at: String,
     pub author_association: String,
     pub body: String,
+    pub reactions: Option<Reactions>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct Discussion {
+    pub id: u64,
+    pub node_id: String,
     pub category: DiscussionCategory,
     pub answer_html_url: Option<String>,
     pub answer_chosen_at: Option<String>,
-    pub answer_chosen_by: Option<String>,
+    pub answer_chosen_by: Option<User>,
     pub html_url: String,
     pub user: User,
     pub number: u32,
@@ -111,6 +133,48 @@ pub struct Discussion {
     pub author_association: String,
     pub active_lock_reason: Option<String>,
     pub body: String,
+    pub reactions: Option<Reactions>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscussionAction {
+    Created,
+    Edited,
+    Deleted,
+    Pinned,
+    Unpinned,
+    Locked,
+    Unlocked,
+    Transferred,
+    CategoryChanged,
+    Answered,
+    Unanswered,
+    Labeled,
+    Unlabeled,
+    Closed,
+    Reopened,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ChangedFrom<T> {
+    pub from: T,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DiscussionChanges {
+    /// The previous category, for `category_changed` events.
+    pub category: Option<ChangedFrom<DiscussionCategory>>,
+    /// The previous title, for `edited` events.
+    pub title: Option<ChangedFrom<String>>,
+    /// The previous body, for `edited` events.
+    pub body: Option<ChangedFrom<String>>,
+    /// The discussion in the new repository, for `transferred` events.
+    pub new_discussion: Option<Discussion>,
+    /// The repository the discussion transferred to, for `transferred` events.
+    pub new_repository: Option<Repository>,
 }
 
 type Fork = Repository;
@@ -122,6 +186,22 @@ pub struct Label {
     pub default: bool,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct Milestone {
+    pub html_url: String,
+    pub number: u32,
+    pub title: String,
+    pub description: Option<String>,
+    pub creator: Option<User>,
+    pub open_issues: u32,
+    pub closed_issues: u32,
+    pub state: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub due_on: Option<String>,
+    pub closed_at: Option<String>,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Issue {
     pub active_lock_reason: Option<String>,
@@ -134,16 +214,19 @@ pub struct Issue {
     pub state_reason: Option<String>,
     pub locked: bool,
     pub assignees: Vec<User>,
+    pub milestone: Option<Milestone>,
     pub comments: u32,
     pub created_at: String,
     pub updated_at: String,
     pub closed_at: Option<String>,
     pub author_association: String,
     pub body: Option<String>,
+    pub reactions: Option<Reactions>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct Plan {
+    pub id: u64,
     pub name: String,
     pub description: String,
     pub monthly_price_in_cents: u32,
@@ -154,16 +237,119 @@ pub struct Plan {
     pub bullets: Vec<String>,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct MarketplaceAccount {
+    pub id: u64,
+    pub login: String,
+    pub r#type: String,
+    pub organization_billing_email: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketplaceAction {
+    Purchased,
+    PendingChange,
+    PendingChangeCancelled,
+    Changed,
+    Cancelled,
+    #[serde(other)]
+    Unknown,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct MarketplacePurchase {
+    pub account: Option<MarketplaceAccount>,
     pub billing_cycle: String,
     pub unit_count: u32,
     pub on_free_trial: bool,
     pub free_trial_ends_on: Option<String>,
-    pub next_billing_date: String,
+    pub next_billing_date: Option<String>,
     pub plan: Plan,
 }
 
+impl MarketplacePurchase {
+    /// The price of one billing cycle in cents,
+    /// multiplied by `unit_count` for per-unit plans.
+    pub fn price_in_cents(&self) -> i64 {
+        let price = match self.billing_cycle.as_str() {
+            "yearly" => self.plan.yearly_price_in_cents,
+            _ => self.plan.monthly_price_in_cents,
+        } as i64;
+
+        match self.plan.price_model.as_str() {
+            "per-unit" | "PER_UNIT" => price * self.unit_count as i64,
+            _ => price,
+        }
+    }
+
+    /// The price difference in cents between this purchase and the `previous` one,
+    /// positive for upgrades and negative for downgrades.
+    #[inline]
+    pub fn price_delta_in_cents(&self, previous: &MarketplacePurchase) -> i64 {
+        self.price_in_cents() - previous.price_in_cents()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SponsorshipAction {
+    Created,
+    Cancelled,
+    Edited,
+    TierChanged,
+    PendingCancellation,
+    PendingTierChange,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PrivacyLevel {
+    Public,
+    Private,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SponsorshipTier {
+    pub node_id: String,
+    pub created_at: String,
+    pub name: String,
+    pub description: String,
+    pub monthly_price_in_cents: u32,
+    pub monthly_price_in_dollars: u32,
+    pub is_one_time: bool,
+    pub is_custom_amount: bool,
+}
+
+impl SponsorshipTier {
+    /// The monthly price difference in cents between this tier and the `previous` one,
+    /// positive for upgrades and negative for downgrades.
+    #[inline]
+    pub fn price_delta_in_cents(&self, previous: &SponsorshipTier) -> i64 {
+        self.monthly_price_in_cents as i64 - previous.monthly_price_in_cents as i64
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Sponsorship {
+    pub node_id: String,
+    pub created_at: String,
+    pub sponsorable: User,
+    pub sponsor: User,
+    pub privacy_level: PrivacyLevel,
+    pub tier: SponsorshipTier,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SponsorshipChanges {
+    /// The previous tier, for `tier_changed` and `pending_tier_change` events.
+    pub tier: Option<ChangedFrom<SponsorshipTier>>,
+    /// The previous privacy level, for `edited` events.
+    pub privacy_level: Option<ChangedFrom<PrivacyLevel>>,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct PrPoint {
     pub label: String,
@@ -192,6 +378,7 @@ pub struct PullRequest {
     pub assignees: Vec<User>,
     pub requested_reviewers: Vec<User>,
     pub labels: Vec<Label>,
+    pub milestone: Option<Milestone>,
     pub head: PrPoint,
     pub base: PrPoint,
     pub author_association: String,
@@ -209,6 +396,115 @@ pub struct PullRequest {
     pub changed_files: Option<u32>,
 }
 
+/// The size bucket of a pull request by its changed lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PullRequestSize {
+    XS,
+    S,
+    M,
+    L,
+    XL,
+}
+
+impl PullRequestSize {
+    /// The label of the size, like `size/XS`.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PullRequestSize::XS => "size/XS",
+            PullRequestSize::S => "size/S",
+            PullRequestSize::M => "size/M",
+            PullRequestSize::L => "size/L",
+            PullRequestSize::XL => "size/XL",
+        }
+    }
+}
+
+/// The maximum changed lines (additions + deletions) of each size bucket,
+/// pull requests larger than `l` are `XL`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeThresholds {
+    pub xs: u32,
+    pub s: u32,
+    pub m: u32,
+    pub l: u32,
+}
+
+impl Default for SizeThresholds {
+    fn default() -> Self {
+        SizeThresholds {
+            xs: 9,
+            s: 29,
+            m: 99,
+            l: 499,
+        }
+    }
+}
+
+impl PullRequest {
+    /// Classifies the pull request by its changed lines,
+    /// `None` if the payload has no `additions` or `deletions`.
+    pub fn size(&self, thresholds: &SizeThresholds) -> Option<PullRequestSize> {
+        let lines = self.additions? + self.deletions?;
+
+        Some(match lines {
+            n if n <= thresholds.xs => PullRequestSize::XS,
+            n if n <= thresholds.s => PullRequestSize::S,
+            n if n <= thresholds.m => PullRequestSize::M,
+            n if n <= thresholds.l => PullRequestSize::L,
+            _ => PullRequestSize::XL,
+        })
+    }
+
+    /// Builds the labels update replacing any existing `size/*` label
+    /// with the one matching the size of the pull request.
+    ///
+    /// eg.
+    /// ```rust
+    /// # use flows_connector_dsi::github::{PullRequest, SizeThresholds};
+    /// # fn label(pr: &PullRequest) -> Option<Result<String, String>> {
+    /// pr.size_label_update(&SizeThresholds::default())
+    ///     .map(|update| update.build())
+    /// # }
+    /// ```
+    pub fn size_label_update<'a>(
+        &self,
+        thresholds: &SizeThresholds,
+    ) -> Option<outbound::OutboundData<'a>> {
+        let size = self.size(thresholds)?;
+        let labels = self
+            .labels
+            .iter()
+            .map(|l| l.name.as_str())
+            .filter(|name| !name.starts_with("size/"))
+            .chain([size.label()])
+            .collect::<Vec<_>>();
+
+        Some(outbound::modify_issue(self.number).labels(labels))
+    }
+
+    /// Whether the pull request is open and not a draft.
+    #[inline]
+    pub fn is_ready_for_review(&self) -> bool {
+        self.state == "open" && self.draft != Some(true)
+    }
+
+    /// Whether the pull request has been merged.
+    #[inline]
+    pub fn is_merged(&self) -> bool {
+        self.merged == Some(true) || self.merged_at.is_some()
+    }
+
+    /// Whether the head branch lives in another repository than the base branch,
+    /// like a pull request from a fork.
+    ///
+    /// GitHub sends a `null` head repository once the fork has been deleted,
+    /// such payloads don't parse into a [`PullRequest`] at all.
+    #[inline]
+    pub fn is_cross_repo(&self) -> bool {
+        self.head.repo.full_name != self.base.repo.full_name
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Review {
     pub user: User,
@@ -247,6 +543,141 @@ pub struct Release {
     pub created_at: String,
     pub published_at: String,
     pub body: Option<String>,
+    pub reactions: Option<Reactions>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ContainerTag {
+    pub name: String,
+    pub digest: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ContainerManifest {
+    pub digest: Option<String>,
+    pub media_type: Option<String>,
+    pub uri: Option<String>,
+    pub size: Option<u64>,
+
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ContainerMetadata {
+    pub tag: Option<ContainerTag>,
+    pub labels: Option<HashMap<String, Value>>,
+    pub manifest: Option<ContainerManifest>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PackageVersion {
+    pub id: u64,
+    pub version: String,
+    pub name: Option<String>,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub html_url: String,
+    pub tag_name: Option<String>,
+    pub target_commitish: Option<String>,
+    pub target_oid: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub author: Option<User>,
+    pub container_metadata: Option<ContainerMetadata>,
+    pub installation_command: Option<String>,
+    pub package_url: Option<String>,
+}
+
+impl PackageVersion {
+    /// The tags of this version, including the container tag and the release tag.
+    pub fn tags(&self) -> Vec<&str> {
+        let mut tags = Vec::new();
+        if let Some(tag) = self
+            .container_metadata
+            .as_ref()
+            .and_then(|m| m.tag.as_ref())
+            .filter(|t| !t.name.is_empty())
+        {
+            tags.push(tag.name.as_str());
+        }
+        if let Some(tag) = self.tag_name.as_deref().filter(|t| !t.is_empty()) {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+
+    /// The digest of the container image.
+    pub fn digest(&self) -> Option<&str> {
+        let metadata = self.container_metadata.as_ref()?;
+        metadata
+            .manifest
+            .as_ref()
+            .and_then(|m| m.digest.as_deref())
+            .or_else(|| metadata.tag.as_ref().map(|t| t.digest.as_str()))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PackageRegistry {
+    pub about_url: String,
+    pub name: String,
+    pub r#type: String,
+    pub url: String,
+    pub vendor: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Package {
+    pub id: u64,
+    pub name: String,
+    pub namespace: String,
+    pub description: Option<String>,
+    pub ecosystem: String,
+    pub package_type: String,
+    pub html_url: String,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    pub owner: User,
+    pub package_version: Option<PackageVersion>,
+    pub registry: Option<PackageRegistry>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WikiPageAction {
+    Created,
+    Edited,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct WikiPage {
+    pub page_name: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub action: WikiPageAction,
+    pub sha: String,
+    pub html_url: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PageBuildError {
+    pub message: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PageBuild {
+    pub url: String,
+    pub status: String,
+    pub error: PageBuildError,
+    pub pusher: Option<User>,
+    pub commit: Option<String>,
+    /// The build duration in milliseconds.
+    pub duration: u64,
+    pub created_at: String,
+    pub updated_at: String,
 }
 
 #[derive(Deserialize, Debug)]
@@ -284,6 +715,7 @@ pub struct GitUser {
 
 #[derive(Deserialize, Debug)]
 pub struct Commit {
+    pub id: String,
     pub distinct: bool,
     #[serde(default)]
     pub message: String,
@@ -296,6 +728,348 @@ pub struct Commit {
     pub modified: Vec<String>,
 }
 
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeGroupAction {
+    ChecksRequested,
+    Destroyed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeGroupDestroyedReason {
+    Merged,
+    Invalidated,
+    Dequeued,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct MergeGroupCommit {
+    pub id: String,
+    pub tree_id: String,
+    pub message: String,
+    pub timestamp: String,
+    pub author: GitUser,
+    pub committer: GitUser,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct MergeGroup {
+    pub head_sha: String,
+    pub head_ref: String,
+    pub base_sha: String,
+    pub base_ref: String,
+    pub head_commit: MergeGroupCommit,
+}
+
+/// The enforcement level of a branch protection setting,
+/// ordered from the least to the most restrictive.
+/// `Unknown` levels are not comparable with the others.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EnforcementLevel {
+    Off,
+    NonAdmins,
+    Everyone,
+    #[serde(other)]
+    Unknown,
+}
+
+impl EnforcementLevel {
+    fn rank(self) -> Option<u8> {
+        match self {
+            EnforcementLevel::Off => Some(0),
+            EnforcementLevel::NonAdmins => Some(1),
+            EnforcementLevel::Everyone => Some(2),
+            EnforcementLevel::Unknown => None,
+        }
+    }
+}
+
+impl PartialOrd for EnforcementLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self.rank(), other.rank()) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+            _ if self == other => Some(std::cmp::Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BranchProtectionRule {
+    pub id: u64,
+    pub repository_id: u64,
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub pull_request_reviews_enforcement_level: EnforcementLevel,
+    pub required_approving_review_count: u32,
+    pub dismiss_stale_reviews_on_push: bool,
+    pub require_code_owner_review: bool,
+    pub authorized_dismissal_actors_only: bool,
+    pub ignore_approvals_from_contributors: bool,
+    pub required_status_checks: Vec<String>,
+    pub required_status_checks_enforcement_level: EnforcementLevel,
+    pub strict_required_status_checks_policy: bool,
+    pub signature_requirement_enforcement_level: EnforcementLevel,
+    pub linear_history_requirement_enforcement_level: EnforcementLevel,
+    pub admin_enforced: bool,
+    pub allow_force_pushes_enforcement_level: EnforcementLevel,
+    pub allow_deletions_enforcement_level: EnforcementLevel,
+    pub merge_queue_enforcement_level: Option<EnforcementLevel>,
+    pub required_deployments_enforcement_level: Option<EnforcementLevel>,
+    pub required_conversation_resolution_level: EnforcementLevel,
+    pub authorized_actors_only: bool,
+    pub authorized_actor_names: Vec<String>,
+    pub require_last_push_approval: Option<bool>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct BranchProtectionRuleChanges {
+    pub admin_enforced: Option<ChangedFrom<bool>>,
+    pub allow_deletions_enforcement_level: Option<ChangedFrom<EnforcementLevel>>,
+    pub allow_force_pushes_enforcement_level: Option<ChangedFrom<EnforcementLevel>>,
+    pub authorized_actor_names: Option<ChangedFrom<Vec<String>>>,
+    pub authorized_actors_only: Option<ChangedFrom<bool>>,
+    pub authorized_dismissal_actors_only: Option<ChangedFrom<bool>>,
+    pub dismiss_stale_reviews_on_push: Option<ChangedFrom<bool>>,
+    pub linear_history_requirement_enforcement_level: Option<ChangedFrom<EnforcementLevel>>,
+    pub merge_queue_enforcement_level: Option<ChangedFrom<EnforcementLevel>>,
+    pub pull_request_reviews_enforcement_level: Option<ChangedFrom<EnforcementLevel>>,
+    pub require_code_owner_review: Option<ChangedFrom<bool>>,
+    pub require_last_push_approval: Option<ChangedFrom<bool>>,
+    pub required_approving_review_count: Option<ChangedFrom<u32>>,
+    pub required_conversation_resolution_level: Option<ChangedFrom<EnforcementLevel>>,
+    pub required_deployments_enforcement_level: Option<ChangedFrom<EnforcementLevel>>,
+    pub required_status_checks: Option<ChangedFrom<Vec<String>>>,
+    pub required_status_checks_enforcement_level: Option<ChangedFrom<EnforcementLevel>>,
+    pub signature_requirement_enforcement_level: Option<ChangedFrom<EnforcementLevel>>,
+    pub strict_required_status_checks_policy: Option<ChangedFrom<bool>>,
+}
+
+impl BranchProtectionRule {
+    /// Lists the names of the settings that became less restrictive
+    /// compared to their previous values in `changes`.
+    pub fn weakened_settings(&self, changes: &BranchProtectionRuleChanges) -> Vec<&'static str> {
+        fn lowered(from: &Option<ChangedFrom<EnforcementLevel>>, to: EnforcementLevel) -> bool {
+            from.as_ref().is_some_and(|c| to < c.from)
+        }
+
+        fn disabled(from: &Option<ChangedFrom<bool>>, to: bool) -> bool {
+            from.as_ref().is_some_and(|c| c.from && !to)
+        }
+
+        let mut weakened = Vec::new();
+        let mut check = |name, is_weakened| {
+            if is_weakened {
+                weakened.push(name);
+            }
+        };
+
+        check(
+            "admin_enforced",
+            disabled(&changes.admin_enforced, self.admin_enforced),
+        );
+        // Raising the `allow_*` levels permits more people to force push or delete.
+        check(
+            "allow_deletions_enforcement_level",
+            changes
+                .allow_deletions_enforcement_level
+                .as_ref()
+                .is_some_and(|c| self.allow_deletions_enforcement_level > c.from),
+        );
+        check(
+            "allow_force_pushes_enforcement_level",
+            changes
+                .allow_force_pushes_enforcement_level
+                .as_ref()
+                .is_some_and(|c| self.allow_force_pushes_enforcement_level > c.from),
+        );
+        check(
+            "authorized_actor_names",
+            changes.authorized_actor_names.as_ref().is_some_and(|c| {
+                self.authorized_actor_names
+                    .iter()
+                    .any(|name| !c.from.contains(name))
+            }),
+        );
+        check(
+            "authorized_actors_only",
+            disabled(&changes.authorized_actors_only, self.authorized_actors_only),
+        );
+        check(
+            "authorized_dismissal_actors_only",
+            disabled(
+                &changes.authorized_dismissal_actors_only,
+                self.authorized_dismissal_actors_only,
+            ),
+        );
+        check(
+            "dismiss_stale_reviews_on_push",
+            disabled(
+                &changes.dismiss_stale_reviews_on_push,
+                self.dismiss_stale_reviews_on_push,
+            ),
+        );
+        check(
+            "linear_history_requirement_enforcement_level",
+            lowered(
+                &changes.linear_history_requirement_enforcement_level,
+                self.linear_history_requirement_enforcement_level,
+            ),
+        );
+        // The settings missing from older payloads are not compared.
+        check(
+            "merge_queue_enforcement_level",
+            self.merge_queue_enforcement_level
+                .is_some_and(|to| lowered(&changes.merge_queue_enforcement_level, to)),
+        );
+        check(
+            "pull_request_reviews_enforcement_level",
+            lowered(
+                &changes.pull_request_reviews_enforcement_level,
+                self.pull_request_reviews_enforcement_level,
+            ),
+        );
+        check(
+            "require_code_owner_review",
+            disabled(
+                &changes.require_code_owner_review,
+                self.require_code_owner_review,
+            ),
+        );
+        check(
+            "require_last_push_approval",
+            self.require_last_push_approval
+                .is_some_and(|to| disabled(&changes.require_last_push_approval, to)),
+        );
+        check(
+            "required_approving_review_count",
+            changes
+                .required_approving_review_count
+                .as_ref()
+                .is_some_and(|c| self.required_approving_review_count < c.from),
+        );
+        check(
+            "required_conversation_resolution_level",
+            lowered(
+                &changes.required_conversation_resolution_level,
+                self.required_conversation_resolution_level,
+            ),
+        );
+        check(
+            "required_deployments_enforcement_level",
+            self.required_deployments_enforcement_level
+                .is_some_and(|to| lowered(&changes.required_deployments_enforcement_level, to)),
+        );
+        check(
+            "required_status_checks",
+            changes.required_status_checks.as_ref().is_some_and(|c| {
+                c.from
+                    .iter()
+                    .any(|check| !self.required_status_checks.contains(check))
+            }),
+        );
+        check(
+            "required_status_checks_enforcement_level",
+            lowered(
+                &changes.required_status_checks_enforcement_level,
+                self.required_status_checks_enforcement_level,
+            ),
+        );
+        check(
+            "signature_requirement_enforcement_level",
+            lowered(
+                &changes.signature_requirement_enforcement_level,
+                self.signature_requirement_enforcement_level,
+            ),
+        );
+        check(
+            "strict_required_status_checks_policy",
+            disabled(
+                &changes.strict_required_status_checks_policy,
+                self.strict_required_status_checks_policy,
+            ),
+        );
+
+        weakened
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct HookConfig {
+    pub content_type: Option<String>,
+    pub insecure_ssl: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Hook {
+    pub r#type: String,
+    pub id: u64,
+    pub name: String,
+    pub active: bool,
+    pub events: Vec<String>,
+    pub config: HookConfig,
+    pub created_at: String,
+    pub updated_at: String,
+    pub app_id: Option<u64>,
+}
+
+/// The differences between the events a hook subscribes to
+/// and the events a flow handles.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct HookEventsReport {
+    /// Events the flow handles but the hook does not subscribe to.
+    pub missing: Vec<String>,
+    /// Events the hook subscribes to but the flow does not handle.
+    pub unexpected: Vec<String>,
+}
+
+impl HookEventsReport {
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.unexpected.is_empty()
+    }
+}
+
+impl Hook {
+    /// Compares the subscribed events with the `handled` events,
+    /// `*` stands for all events on either side and is never reported itself.
+    pub fn check_events<S: AsRef<str>>(&self, handled: &[S]) -> HookEventsReport {
+        let handled = handled.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        let subscribes_all = self.events.iter().any(|e| e == "*");
+        let handles_all = handled.contains(&"*");
+
+        HookEventsReport {
+            missing: handled
+                .iter()
+                .filter(|&&e| e != "*" && !subscribes_all && !self.events.iter().any(|s| s == e))
+                .map(|e| e.to_string())
+                .collect(),
+            unexpected: self
+                .events
+                .iter()
+                .filter(|e| *e != "*" && !handles_all && !handled.contains(&e.as_str()))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+/// The `ping` event sent when a webhook is created, borrowed from the [`InboundData`].
+#[derive(Debug, Clone, Copy)]
+pub struct Ping<'a> {
+    pub zen: &'a str,
+    pub hook_id: u64,
+    pub hook: &'a Hook,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct InboundData {
     pub sender: User,
@@ -305,17 +1079,31 @@ pub struct InboundData {
     pub organization: Option<Organization>,
     pub comment: Option<Comment>,
     pub discussion: Option<Discussion>,
+    pub answer: Option<Comment>,
     pub forkee: Option<Fork>,
     pub issue: Option<Issue>,
     pub label: Option<Label>,
     pub marketplace_purchase: Option<MarketplacePurchase>,
+    pub merge_group: Option<MergeGroup>,
+    pub previous_marketplace_purchase: Option<MarketplacePurchase>,
+    pub effective_date: Option<String>,
+    pub milestone: Option<Milestone>,
+    pub package: Option<Package>,
+    pub pages: Option<Vec<WikiPage>>,
+    pub build: Option<PageBuild>,
     pub pull_request: Option<PullRequest>,
+    pub registry_package: Option<Package>,
     pub review: Option<Review>,
     pub release: Option<Release>,
+    pub rule: Option<BranchProtectionRule>,
+    pub sponsorship: Option<Sponsorship>,
     pub starred_at: Option<String>,
     pub workflow_job: Option<WorkflowJob>,
     pub head_commit: Option<Commit>,
     pub commits: Option<Vec<Commit>>,
+    pub hook: Option<Hook>,
+    pub hook_id: Option<u64>,
+    pub zen: Option<String>,
 
     #[serde(flatten)]
     pub extra: HashMap<String, Value>,
@@ -363,11 +1151,63 @@ impl InboundData {
             .ok_or("Missing discussion".to_string())
     }
 
+    /// Gets the action of a `discussion` event,
+    /// fails on `discussion_comment` events.
+    pub fn get_discussion_action(&self) -> Result<DiscussionAction, String> {
+        if self.discussion.is_none() || self.comment.is_some() {
+            return Err("Not a discussion event".to_string());
+        }
+
+        serde_json::from_value(Value::String(self.get_action()?.clone())).map_err(|e| e.to_string())
+    }
+
+    /// Gets the `changes` of a `discussion` event.
+    #[inline]
+    pub fn get_discussion_changes(&self) -> Result<DiscussionChanges, String> {
+        self.get(&"changes")
+    }
+
+    /// Gets the chosen answer of a `discussion` `answered` event.
+    #[inline]
+    pub fn get_answer(&self) -> Result<&Comment, String> {
+        self.answer.as_ref().ok_or("Missing answer".to_string())
+    }
+
     #[inline]
     pub fn get_fork(&self) -> Result<&Fork, String> {
         self.forkee.as_ref().ok_or("Missing forkee".to_string())
     }
 
+    #[inline]
+    pub fn get_hook(&self) -> Result<&Hook, String> {
+        self.hook.as_ref().ok_or("Missing hook".to_string())
+    }
+
+    #[inline]
+    pub fn get_hook_id(&self) -> Result<u64, String> {
+        self.hook_id.ok_or("Missing hook_id".to_string())
+    }
+
+    #[inline]
+    pub fn get_zen(&self) -> Result<&String, String> {
+        self.zen.as_ref().ok_or("Missing zen".to_string())
+    }
+
+    /// The `ping` event, only the ping event carries `zen`.
+    pub fn get_ping(&self) -> Result<Ping<'_>, String> {
+        Ok(Ping {
+            zen: self.get_zen()?,
+            hook_id: self.get_hook_id()?,
+            hook: self.get_hook()?,
+        })
+    }
+
+    /// Whether this is the `ping` event sent when a webhook is created.
+    #[inline]
+    pub fn is_ping(&self) -> bool {
+        self.zen.is_some()
+    }
+
     #[inline]
     pub fn get_issue(&self) -> Result<&Issue, String> {
         self.issue.as_ref().ok_or("Missing issue".to_string())
@@ -386,20 +1226,150 @@ impl InboundData {
     }
 
     #[inline]
-    pub fn get_pull_request(&self) -> Result<&PullRequest, String> {
-        self.pull_request
+    pub fn get_previous_marketplace_purchase(&self) -> Result<&MarketplacePurchase, String> {
+        self.previous_marketplace_purchase
             .as_ref()
-            .ok_or("Missing pull_request".to_string())
+            .ok_or("Missing previous_marketplace_purchase".to_string())
     }
 
     #[inline]
-    pub fn get_review(&self) -> Result<&Review, String> {
-        self.review.as_ref().ok_or("Missing review".to_string())
+    pub fn get_effective_date(&self) -> Result<&String, String> {
+        self.effective_date
+            .as_ref()
+            .ok_or("Missing effective_date".to_string())
     }
 
-    #[inline]
-    pub fn get_release(&self) -> Result<&Release, String> {
-        self.release.as_ref().ok_or("Missing release".to_string())
+    /// Gets the action of a `marketplace_purchase` event.
+    pub fn get_marketplace_action(&self) -> Result<MarketplaceAction, String> {
+        self.get_marketplace_purchase()?;
+
+        serde_json::from_value(Value::String(self.get_action()?.clone())).map_err(|e| e.to_string())
+    }
+
+    /// Gets the price difference in cents between the previous and the new purchase
+    /// of a `marketplace_purchase` `changed` or `pending_change` event.
+    pub fn get_marketplace_price_delta(&self) -> Result<i64, String> {
+        Ok(self
+            .get_marketplace_purchase()?
+            .price_delta_in_cents(self.get_previous_marketplace_purchase()?))
+    }
+
+    #[inline]
+    pub fn get_merge_group(&self) -> Result<&MergeGroup, String> {
+        self.merge_group
+            .as_ref()
+            .ok_or("Missing merge_group".to_string())
+    }
+
+    /// Gets the action of a `merge_group` event.
+    pub fn get_merge_group_action(&self) -> Result<MergeGroupAction, String> {
+        self.get_merge_group()?;
+
+        serde_json::from_value(Value::String(self.get_action()?.clone())).map_err(|e| e.to_string())
+    }
+
+    /// Gets the reason of a `merge_group` `destroyed` event.
+    #[inline]
+    pub fn get_merge_group_destroyed_reason(&self) -> Result<MergeGroupDestroyedReason, String> {
+        self.get(&"reason")
+    }
+
+    #[inline]
+    pub fn get_milestone(&self) -> Result<&Milestone, String> {
+        self.milestone
+            .as_ref()
+            .ok_or("Missing milestone".to_string())
+    }
+
+    #[inline]
+    pub fn get_package(&self) -> Result<&Package, String> {
+        self.package.as_ref().ok_or("Missing package".to_string())
+    }
+
+    #[inline]
+    pub fn get_pages(&self) -> Result<&Vec<WikiPage>, String> {
+        self.pages.as_ref().ok_or("Missing pages".to_string())
+    }
+
+    #[inline]
+    pub fn get_page_build(&self) -> Result<&PageBuild, String> {
+        self.build.as_ref().ok_or("Missing build".to_string())
+    }
+
+    #[inline]
+    pub fn get_pull_request(&self) -> Result<&PullRequest, String> {
+        self.pull_request
+            .as_ref()
+            .ok_or("Missing pull_request".to_string())
+    }
+
+    #[inline]
+    pub fn get_registry_package(&self) -> Result<&Package, String> {
+        self.registry_package
+            .as_ref()
+            .ok_or("Missing registry_package".to_string())
+    }
+
+    #[inline]
+    pub fn get_review(&self) -> Result<&Review, String> {
+        self.review.as_ref().ok_or("Missing review".to_string())
+    }
+
+    #[inline]
+    pub fn get_release(&self) -> Result<&Release, String> {
+        self.release.as_ref().ok_or("Missing release".to_string())
+    }
+
+    #[inline]
+    pub fn get_branch_protection_rule(&self) -> Result<&BranchProtectionRule, String> {
+        self.rule.as_ref().ok_or("Missing rule".to_string())
+    }
+
+    /// Gets the `changes` of a `branch_protection_rule` `edited` event.
+    #[inline]
+    pub fn get_branch_protection_rule_changes(
+        &self,
+    ) -> Result<BranchProtectionRuleChanges, String> {
+        self.get(&"changes")
+    }
+
+    /// Lists the settings weakened by a `branch_protection_rule` `edited` event.
+    pub fn get_weakened_branch_protections(&self) -> Result<Vec<&'static str>, String> {
+        Ok(self
+            .get_branch_protection_rule()?
+            .weakened_settings(&self.get_branch_protection_rule_changes()?))
+    }
+
+    #[inline]
+    pub fn get_sponsorship(&self) -> Result<&Sponsorship, String> {
+        self.sponsorship
+            .as_ref()
+            .ok_or("Missing sponsorship".to_string())
+    }
+
+    /// Gets the action of a `sponsorship` event.
+    pub fn get_sponsorship_action(&self) -> Result<SponsorshipAction, String> {
+        self.get_sponsorship()?;
+
+        serde_json::from_value(Value::String(self.get_action()?.clone())).map_err(|e| e.to_string())
+    }
+
+    /// Gets the `changes` of a `sponsorship` event.
+    #[inline]
+    pub fn get_sponsorship_changes(&self) -> Result<SponsorshipChanges, String> {
+        self.get(&"changes")
+    }
+
+    /// Gets the monthly price difference in cents between the previous and the new tier
+    /// of a `sponsorship` `tier_changed` or `pending_tier_change` event.
+    pub fn get_sponsorship_price_delta(&self) -> Result<i64, String> {
+        let previous = self
+            .get_sponsorship_changes()?
+            .tier
+            .ok_or("Missing changes.tier".to_string())?
+            .from;
+
+        Ok(self.get_sponsorship()?.tier.price_delta_in_cents(&previous))
     }
 
     #[inline]
@@ -444,6 +1414,155 @@ pub mod outbound {
     use serde::Serialize;
     use serde_json::{json, Value};
 
+    /// The maximum length of a commit status description accepted by GitHub.
+    pub const MAX_STATUS_DESCRIPTION_LEN: usize = 140;
+
+    /// The maximum length of the summary and text of a check run output accepted by GitHub.
+    pub const MAX_CHECK_RUN_OUTPUT_LEN: usize = 65535;
+
+    /// The state of a commit status.
+    #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum CommitState {
+        Error,
+        Failure,
+        Pending,
+        Success,
+    }
+
+    /// The current status of a check run.
+    #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum CheckStatus {
+        Queued,
+        InProgress,
+        Completed,
+    }
+
+    /// The final conclusion of a completed check run.
+    #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum CheckConclusion {
+        ActionRequired,
+        Cancelled,
+        Failure,
+        Neutral,
+        Success,
+        Skipped,
+        TimedOut,
+    }
+
+    /// The severity level of a check run annotation.
+    #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum AnnotationLevel {
+        Notice,
+        Warning,
+        Failure,
+    }
+
+    /// An annotation attached to a specific line range of a file.
+    #[derive(Serialize, Debug)]
+    pub struct Annotation {
+        path: String,
+        start_line: u32,
+        end_line: u32,
+        annotation_level: AnnotationLevel,
+        message: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+    }
+
+    impl Annotation {
+        /// Create an annotation on the lines `start_line..=end_line` of `path`.
+        pub fn new<P: Into<String>, M: Into<String>>(
+            path: P,
+            start_line: u32,
+            end_line: u32,
+            level: AnnotationLevel,
+            message: M,
+        ) -> Annotation {
+            Annotation {
+                path: path.into(),
+                start_line,
+                end_line,
+                annotation_level: level,
+                message: message.into(),
+                title: None,
+            }
+        }
+
+        /// Set the title of the annotation.
+        pub fn title<S: Into<String>>(mut self, title: S) -> Annotation {
+            self.title = Some(title.into());
+            self
+        }
+    }
+
+    /// The output of a check run.
+    #[derive(Serialize, Debug)]
+    pub struct CheckRunOutput {
+        title: String,
+        summary: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        annotations: Vec<Annotation>,
+    }
+
+    impl CheckRunOutput {
+        /// Create a check run output with the title and summary (supports Markdown).
+        pub fn new<T: Into<String>, S: Into<String>>(title: T, summary: S) -> CheckRunOutput {
+            CheckRunOutput {
+                title: title.into(),
+                summary: summary.into(),
+                text: None,
+                annotations: Vec::new(),
+            }
+        }
+
+        /// Set the details of the check run (supports Markdown).
+        pub fn text<S: Into<String>>(mut self, text: S) -> CheckRunOutput {
+            self.text = Some(text.into());
+            self
+        }
+
+        /// Add an annotation.
+        pub fn annotation(mut self, annotation: Annotation) -> CheckRunOutput {
+            self.annotations.push(annotation);
+            self
+        }
+    }
+
+    /// The kind of a reaction.
+    #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ReactionContent {
+        #[serde(rename = "+1")]
+        PlusOne,
+        #[serde(rename = "-1")]
+        MinusOne,
+        Laugh,
+        Confused,
+        Heart,
+        Hooray,
+        Rocket,
+        Eyes,
+    }
+
+    /// The subject that a reaction is added to.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum ReactionTarget {
+        /// An issue or a pull request by its number.
+        Issue(u32),
+        /// An issue or pull request comment by its id.
+        IssueComment(u64),
+        /// A pull request review comment by its id.
+        ReviewComment(u64),
+        /// A discussion comment by its GraphQL node id.
+        DiscussionComment(String),
+    }
+
     #[derive(Serialize)]
     pub struct OutboundData<'a> {
         #[serde(flatten)]
@@ -451,15 +1570,15 @@ pub mod outbound {
     }
 
     impl<'a> OutboundData<'a> {
-        /// Set the issue or issue comment body.
+        /// Set the issue, issue comment or discussion comment body.
         pub fn body<S: ToString + Serialize>(mut self, body: S) -> OutboundData<'a> {
             self.inner.insert("body", json!(body));
             self
         }
 
-        /// Set Milestone.
-        pub fn milestone(mut self, milestone: Value) -> OutboundData<'a> {
-            self.inner.insert("milestone", milestone);
+        /// Set Milestone by its number, `None` to clear it.
+        pub fn milestone(mut self, milestone: Option<u32>) -> OutboundData<'a> {
+            self.inner.insert("milestone", json!(milestone));
             self
         }
 
@@ -505,6 +1624,84 @@ pub mod outbound {
         }
     }
 
+    /// The outbound data of [`modify_discussion`].
+    #[derive(Serialize)]
+    pub struct DiscussionOutboundData<'a> {
+        #[serde(flatten)]
+        inner: HashMap<&'a str, Value>,
+    }
+
+    impl<'a> DiscussionOutboundData<'a> {
+        /// Set the discussion body.
+        pub fn body<S: ToString + Serialize>(mut self, body: S) -> DiscussionOutboundData<'a> {
+            self.inner.insert("body", json!(body));
+            self
+        }
+
+        /// Mark the discussion comment as the answer of the discussion.
+        pub fn answer<S: ToString + Serialize>(
+            mut self,
+            comment_node_id: S,
+        ) -> DiscussionOutboundData<'a> {
+            self.inner
+                .insert("answer_comment_id", json!(comment_node_id));
+            self
+        }
+
+        /// Move the discussion into another category.
+        pub fn category<S: ToString + Serialize>(
+            mut self,
+            category_node_id: S,
+        ) -> DiscussionOutboundData<'a> {
+            self.inner.insert("category_id", json!(category_node_id));
+            self
+        }
+
+        /// Build outbound JSON data.
+        pub fn build(self) -> Result<String, String> {
+            if self.inner.len() < 2 {
+                return Err("OutboundData build failed: Too few fields".to_string());
+            }
+
+            serde_json::to_string(&self).map_err(|e| format!("OutboundData build failed: {}", e))
+        }
+    }
+
+    /// Modified a discussion like edit the body,
+    /// mark an answer or change the category.
+    ///
+    /// eg.
+    /// ```rust
+    /// use flows_connector_dsi::github::outbound;
+    ///
+    /// outbound::modify_discussion(90)
+    ///     .answer("MDE3OkRpc2N1c3Npb25Db21tZW50NTQ0MDc4")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn modify_discussion<'a>(discussion_number: u32) -> DiscussionOutboundData<'a> {
+        DiscussionOutboundData {
+            inner: [("discussion_number", json!(discussion_number))]
+                .into_iter()
+                .collect::<HashMap<&str, Value>>(),
+        }
+    }
+
+    /// Create a comment on a discussion.
+    pub fn create_discussion_comment<'a, S: ToString + Serialize>(
+        discussion_number: u32,
+        body: S,
+    ) -> OutboundData<'a> {
+        OutboundData {
+            inner: [
+                ("discussion_number", json!(discussion_number)),
+                ("body", json!(body)),
+            ]
+            .into_iter()
+            .collect::<HashMap<&str, Value>>(),
+        }
+    }
+
     /// Merge a pull request.
     pub fn merge_pull<'a>(pull_number: u32) -> OutboundData<'a> {
         OutboundData {
@@ -513,4 +1710,560 @@ pub mod outbound {
                 .collect::<HashMap<&str, Value>>(),
         }
     }
+
+    /// The outbound data of [`create_commit_status`].
+    #[derive(Serialize)]
+    pub struct CommitStatusOutboundData<'a> {
+        #[serde(flatten)]
+        inner: HashMap<&'a str, Value>,
+    }
+
+    impl<'a> CommitStatusOutboundData<'a> {
+        /// Set the label to differentiate this commit status from
+        /// the statuses of other systems.
+        pub fn context<S: ToString + Serialize>(
+            mut self,
+            context: S,
+        ) -> CommitStatusOutboundData<'a> {
+            self.inner.insert("context", json!(context));
+            self
+        }
+
+        /// Set the target URL to associate with the commit status.
+        pub fn target_url<S: ToString + Serialize>(
+            mut self,
+            target_url: S,
+        ) -> CommitStatusOutboundData<'a> {
+            self.inner.insert("target_url", json!(target_url));
+            self
+        }
+
+        /// Set a short description of the commit status,
+        /// at most [`MAX_STATUS_DESCRIPTION_LEN`] characters.
+        pub fn description<S: ToString + Serialize>(
+            mut self,
+            description: S,
+        ) -> CommitStatusOutboundData<'a> {
+            self.inner.insert("description", json!(description));
+            self
+        }
+
+        /// Build outbound JSON data.
+        pub fn build(self) -> Result<String, String> {
+            if let Some(Value::String(description)) = self.inner.get("description") {
+                if description.chars().count() > MAX_STATUS_DESCRIPTION_LEN {
+                    return Err(format!(
+                        "OutboundData build failed: description exceeds {} characters",
+                        MAX_STATUS_DESCRIPTION_LEN
+                    ));
+                }
+            }
+
+            serde_json::to_string(&self).map_err(|e| format!("OutboundData build failed: {}", e))
+        }
+    }
+
+    /// Create a commit status for the commit `sha`.
+    ///
+    /// eg.
+    /// ```rust
+    /// use flows_connector_dsi::github::outbound::{self, CommitState};
+    ///
+    /// outbound::create_commit_status("6dcb09b5b57875f334f61aebed695e2e4193db5e", CommitState::Success)
+    ///     .context("ci/lint")
+    ///     .description("No lint errors")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn create_commit_status<'a, S: ToString + Serialize>(
+        sha: S,
+        state: CommitState,
+    ) -> CommitStatusOutboundData<'a> {
+        CommitStatusOutboundData {
+            inner: [("sha", json!(sha)), ("state", json!(state))]
+                .into_iter()
+                .collect::<HashMap<&str, Value>>(),
+        }
+    }
+
+    /// The outbound data of [`create_check_run`].
+    #[derive(Serialize)]
+    pub struct CheckRunOutboundData<'a> {
+        #[serde(flatten)]
+        inner: HashMap<&'a str, Value>,
+    }
+
+    impl<'a> CheckRunOutboundData<'a> {
+        /// Set the current status of the check run,
+        /// use [`CheckRunOutboundData::conclusion`] to complete it.
+        pub fn status(mut self, status: CheckStatus) -> CheckRunOutboundData<'a> {
+            self.inner.insert("status", json!(status));
+            self
+        }
+
+        /// Set the final conclusion of the check run,
+        /// this also marks the check run as `completed`.
+        pub fn conclusion(mut self, conclusion: CheckConclusion) -> CheckRunOutboundData<'a> {
+            self.inner.insert("status", json!(CheckStatus::Completed));
+            self.inner.insert("conclusion", json!(conclusion));
+            self
+        }
+
+        /// Set the URL of the integrator's site that has the full details of the check.
+        pub fn details_url<S: ToString + Serialize>(
+            mut self,
+            details_url: S,
+        ) -> CheckRunOutboundData<'a> {
+            self.inner.insert("details_url", json!(details_url));
+            self
+        }
+
+        /// Set the output of the check run.
+        pub fn output(mut self, output: CheckRunOutput) -> CheckRunOutboundData<'a> {
+            self.inner.insert("output", json!(output));
+            self
+        }
+
+        /// Build outbound JSON data.
+        pub fn build(self) -> Result<String, String> {
+            let completed = self.inner.get("status") == Some(&json!(CheckStatus::Completed));
+            match (completed, self.inner.contains_key("conclusion")) {
+                (true, false) => {
+                    return Err(
+                        "OutboundData build failed: completed check run without conclusion"
+                            .to_string(),
+                    )
+                }
+                (false, true) => {
+                    return Err(
+                        "OutboundData build failed: conclusion on a check run not completed"
+                            .to_string(),
+                    )
+                }
+                _ => {}
+            }
+
+            if let Some(output) = self.inner.get("output") {
+                for key in ["summary", "text"] {
+                    let len = output[key].as_str().map_or(0, |s| s.chars().count());
+                    if len > MAX_CHECK_RUN_OUTPUT_LEN {
+                        return Err(format!(
+                            "OutboundData build failed: output {} exceeds {} characters",
+                            key, MAX_CHECK_RUN_OUTPUT_LEN
+                        ));
+                    }
+                }
+            }
+
+            serde_json::to_string(&self).map_err(|e| format!("OutboundData build failed: {}", e))
+        }
+    }
+
+    /// Create a check run with the name for the commit `head_sha`.
+    ///
+    /// eg.
+    /// ```rust
+    /// use flows_connector_dsi::github::outbound::{
+    ///     self, Annotation, AnnotationLevel, CheckConclusion, CheckRunOutput,
+    /// };
+    ///
+    /// outbound::create_check_run("lint", "6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .conclusion(CheckConclusion::Failure)
+    ///     .output(
+    ///         CheckRunOutput::new("1 error", "Found 1 lint error").annotation(Annotation::new(
+    ///             "src/lib.rs",
+    ///             1,
+    ///             1,
+    ///             AnnotationLevel::Failure,
+    ///             "unused import",
+    ///         )),
+    ///     )
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn create_check_run<'a, N: ToString + Serialize, S: ToString + Serialize>(
+        name: N,
+        head_sha: S,
+    ) -> CheckRunOutboundData<'a> {
+        CheckRunOutboundData {
+            inner: [("name", json!(name)), ("head_sha", json!(head_sha))]
+                .into_iter()
+                .collect::<HashMap<&str, Value>>(),
+        }
+    }
+
+    /// Add a reaction to an issue, comment or discussion comment.
+    ///
+    /// eg.
+    /// ```rust
+    /// use flows_connector_dsi::github::outbound::{self, ReactionContent, ReactionTarget};
+    ///
+    /// outbound::create_reaction(ReactionTarget::IssueComment(1), ReactionContent::Heart)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn create_reaction<'a>(
+        target: ReactionTarget,
+        content: ReactionContent,
+    ) -> OutboundData<'a> {
+        let target = match target {
+            ReactionTarget::Issue(n) => ("issue_number", json!(n)),
+            ReactionTarget::IssueComment(id) => ("comment_id", json!(id)),
+            ReactionTarget::ReviewComment(id) => ("review_comment_id", json!(id)),
+            ReactionTarget::DiscussionComment(node_id) => {
+                ("discussion_comment_node_id", json!(node_id))
+            }
+        };
+
+        OutboundData {
+            inner: [target, ("content", json!(content))]
+                .into_iter()
+                .collect::<HashMap<&str, Value>>(),
+        }
+    }
+}
+
+/// Renders GitHub events into short human-readable messages
+/// for chat notifications.
+pub mod summary {
+    use super::{Commit, InboundData};
+
+    /// The maximum number of characters of a body kept in the multi-line summary.
+    pub const MAX_EXCERPT_LEN: usize = 280;
+
+    /// The maximum number of commits listed in the multi-line summary of a push.
+    pub const MAX_LISTED_COMMITS: usize = 5;
+
+    /// The Markdown dialect of the rendered summary.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Flavor {
+        /// [CommonMark](https://commonmark.org).
+        CommonMark,
+        /// Telegram [MarkdownV2](https://core.telegram.org/bots/api#markdownv2-style),
+        /// to be sent with `ParseMode::MarkdownV2`.
+        TelegramMarkdownV2,
+        /// Discord-flavored Markdown.
+        Discord,
+    }
+
+    /// The rendered summary of an event.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Summary {
+        /// A single line suitable for a notification title.
+        pub one_line: String,
+        /// The one-line summary followed by the details of the event.
+        pub multi_line: String,
+    }
+
+    /// Escapes the characters which have special meanings in the `flavor`,
+    /// including list markers like `-`, `+` and `1.` at the start of a line.
+    pub fn escape(text: &str, flavor: Flavor) -> String {
+        let special: &[char] = match flavor {
+            Flavor::CommonMark => &[
+                '\\', '`', '*', '_', '[', ']', '(', ')', '<', '>', '#', '!', '|', '~',
+            ],
+            Flavor::TelegramMarkdownV2 => &[
+                '\\', '_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{',
+                '}', '.', '!',
+            ],
+            Flavor::Discord => &['\\', '*', '_', '~', '`', '|', '>', '[', ']', '(', ')', '#'],
+        };
+
+        let escaped = escape_chars(text, special);
+        match flavor {
+            // MarkdownV2 escapes `-`, `+` and `.` everywhere.
+            Flavor::TelegramMarkdownV2 => escaped,
+            Flavor::CommonMark | Flavor::Discord => escaped
+                .split('\n')
+                .map(escape_list_marker)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+
+    /// Escapes the list marker at the start of the line, if any.
+    fn escape_list_marker(line: &str) -> String {
+        let indent = line.len() - line.trim_start().len();
+        let rest = &line[indent..];
+        let is_marker_end = |s: &str| s.is_empty() || s.starts_with(' ') || s.starts_with('\t');
+
+        if (rest.starts_with('-') || rest.starts_with('+')) && is_marker_end(&rest[1..]) {
+            return format!("{}\\{}", &line[..indent], rest);
+        }
+
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits > 0
+            && (rest[digits..].starts_with('.') || rest[digits..].starts_with(')'))
+            && is_marker_end(&rest[digits + 1..])
+        {
+            let split = indent + digits;
+            return format!("{}\\{}", &line[..split], &line[split..]);
+        }
+
+        line.to_string()
+    }
+
+    fn escape_chars(text: &str, special: &[char]) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            if special.contains(&c) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    struct Writer {
+        flavor: Flavor,
+    }
+
+    impl Writer {
+        fn text(&self, text: &str) -> String {
+            escape(text, self.flavor)
+        }
+
+        fn bold(&self, text: &str) -> String {
+            match self.flavor {
+                Flavor::TelegramMarkdownV2 => format!("*{}*", self.text(text)),
+                Flavor::CommonMark | Flavor::Discord => format!("**{}**", self.text(text)),
+            }
+        }
+
+        fn code(&self, text: &str) -> String {
+            match self.flavor {
+                Flavor::TelegramMarkdownV2 => format!("`{}`", escape_chars(text, &['\\', '`'])),
+                Flavor::CommonMark | Flavor::Discord if !text.contains('`') => {
+                    format!("`{}`", text)
+                }
+                Flavor::CommonMark | Flavor::Discord => self.text(text),
+            }
+        }
+
+        fn link(&self, text: &str, url: &str) -> String {
+            format!(
+                "[{}]({})",
+                self.text(text),
+                escape_chars(url, &['\\', '(', ')'])
+            )
+        }
+
+        fn excerpt(&self, body: &str) -> String {
+            let body = body.trim();
+            let mut excerpt = body.chars().take(MAX_EXCERPT_LEN).collect::<String>();
+            if excerpt.len() < body.len() {
+                excerpt.push('…');
+            }
+            self.text(&excerpt)
+        }
+    }
+
+    /// Renders the summary of the event, supported events are
+    /// issue opened, pull request merged, release published,
+    /// workflow job failed and push.
+    ///
+    /// eg.
+    /// ```rust
+    /// use flows_connector_dsi::github::{self, summary::{self, Flavor}};
+    ///
+    /// # let payload = String::new();
+    /// if let Ok(summary) = github::inbound(payload)
+    ///     .and_then(|data| summary::render(&data, Flavor::TelegramMarkdownV2))
+    /// {
+    ///     println!("{}", summary.one_line);
+    /// }
+    /// ```
+    pub fn render(data: &InboundData, flavor: Flavor) -> Result<Summary, String> {
+        let w = Writer { flavor };
+        let action = data.action.as_deref();
+
+        let (one_line, details) = if let Some(commits) = &data.commits {
+            render_push(&w, data, commits)?
+        } else if let (Some("opened"), Some(issue), None) = (action, &data.issue, &data.comment) {
+            let one_line = format!(
+                "{}{} opened issue {}",
+                repo_prefix(&w, data),
+                w.bold(&data.sender.login),
+                w.link(
+                    &format!("#{} {}", issue.number, issue.title),
+                    &issue.html_url
+                )
+            );
+
+            let mut details = Vec::new();
+            if !issue.labels.is_empty() {
+                let labels = issue
+                    .labels
+                    .iter()
+                    .map(|l| w.code(&l.name))
+                    .collect::<Vec<_>>();
+                details.push(format!("{} {}", w.text("Labels:"), labels.join(", ")));
+            }
+            if let Some(body) = issue.body.as_deref().filter(|b| !b.trim().is_empty()) {
+                details.push(w.excerpt(body));
+            }
+            (one_line, details)
+        } else if let (Some("closed"), Some(pr)) = (action, &data.pull_request) {
+            if !pr.is_merged() {
+                return Err(
+                    "Unsupported event for summary: pull request closed without merge".to_string(),
+                );
+            }
+
+            let one_line = format!(
+                "{}{} merged pull request {} into {}",
+                repo_prefix(&w, data),
+                w.bold(&pr.merged_by.as_ref().unwrap_or(&data.sender).login),
+                w.link(&format!("#{} {}", pr.number, pr.title), &pr.html_url),
+                w.code(&pr.base.r#ref)
+            );
+
+            let mut details = Vec::new();
+            if let (Some(additions), Some(deletions), Some(changed_files)) =
+                (pr.additions, pr.deletions, pr.changed_files)
+            {
+                details.push(w.text(&format!(
+                    "+{} -{} in {} file(s)",
+                    additions, deletions, changed_files
+                )));
+            }
+            if let Some(commits) = pr.commits {
+                details.push(w.text(&format!("{} commit(s)", commits)));
+            }
+            (one_line, details)
+        } else if let (Some("published"), Some(release)) = (action, &data.release) {
+            let one_line = format!(
+                "{}{} published {}release {}",
+                repo_prefix(&w, data),
+                w.bold(&data.sender.login),
+                if release.prerelease { "pre" } else { "" },
+                w.link(
+                    release.name.as_deref().unwrap_or(&release.tag_name),
+                    &release.html_url
+                )
+            );
+
+            let mut details = vec![format!("{} {}", w.text("Tag:"), w.code(&release.tag_name))];
+            if !release.assets.is_empty() {
+                details.push(w.text(&format!("{} asset(s)", release.assets.len())));
+            }
+            if let Some(body) = release.body.as_deref().filter(|b| !b.trim().is_empty()) {
+                details.push(w.excerpt(body));
+            }
+            (one_line, details)
+        } else if let (Some("completed"), Some(job)) = (action, &data.workflow_job) {
+            if job.conclusion.as_deref() != Some("failure") {
+                return Err("Unsupported event for summary: workflow job did not fail".to_string());
+            }
+
+            let one_line = format!(
+                "{}Workflow job {} failed",
+                repo_prefix(&w, data),
+                w.link(&job.name, &job.html_url)
+            );
+
+            let details = job
+                .steps
+                .iter()
+                .filter(|step| step.conclusion.as_deref() == Some("failure"))
+                .map(|step| {
+                    format!(
+                        "{} {}",
+                        w.text(&format!("- Step {} failed:", step.number)),
+                        w.code(&step.name)
+                    )
+                })
+                .collect();
+            (one_line, details)
+        } else {
+            return Err("Unsupported event for summary".to_string());
+        };
+
+        let multi_line = if details.is_empty() {
+            one_line.clone()
+        } else {
+            format!("{}\n\n{}", one_line, details.join("\n"))
+        };
+
+        Ok(Summary {
+            one_line,
+            multi_line,
+        })
+    }
+
+    fn repo_prefix(w: &Writer, data: &InboundData) -> String {
+        data.repository
+            .as_ref()
+            .map(|r| format!("{} ", w.text(&format!("[{}]", r.full_name))))
+            .unwrap_or_default()
+    }
+
+    fn render_push(
+        w: &Writer,
+        data: &InboundData,
+        commits: &[Commit],
+    ) -> Result<(String, Vec<String>), String> {
+        let r#ref: String = data.get(&"ref")?;
+        let branch = r#ref
+            .strip_prefix("refs/heads/")
+            .or_else(|| r#ref.strip_prefix("refs/tags/"))
+            .unwrap_or(&r#ref);
+        let flag = |key: &str| data.get::<bool, _>(&key).unwrap_or_default();
+        let actor = format!("{}{}", repo_prefix(w, data), w.bold(&data.sender.login));
+
+        if flag("deleted") {
+            return Ok((format!("{} deleted {}", actor, w.code(branch)), vec![]));
+        }
+        if commits.is_empty() {
+            let action = if flag("created") {
+                "created"
+            } else if flag("forced") {
+                "force-pushed"
+            } else {
+                "pushed to"
+            };
+            return Ok((format!("{} {} {}", actor, action, w.code(branch)), vec![]));
+        }
+
+        let pushed = format!(
+            "{} commit{}",
+            commits.len(),
+            if commits.len() == 1 { "" } else { "s" }
+        );
+        let one_line = format!(
+            "{} {} {} to {}",
+            actor,
+            if flag("forced") {
+                "force-pushed"
+            } else {
+                "pushed"
+            },
+            match data.get::<String, _>(&"compare") {
+                Ok(compare) => w.link(&pushed, &compare),
+                Err(_) => w.text(&pushed),
+            },
+            w.code(branch)
+        );
+
+        let mut details = commits
+            .iter()
+            .take(MAX_LISTED_COMMITS)
+            .map(|c| {
+                format!(
+                    "{} {} {}",
+                    w.link(&c.id.chars().take(7).collect::<String>(), &c.url),
+                    w.text(c.message.lines().next().unwrap_or_default()),
+                    w.text(&format!("- {}", c.author.name))
+                )
+            })
+            .collect::<Vec<_>>();
+        if commits.len() > MAX_LISTED_COMMITS {
+            details.push(w.text(&format!(
+                "… and {} more",
+                commits.len() - MAX_LISTED_COMMITS
+            )));
+        }
+
+        Ok((one_line, details))
+    }
 }
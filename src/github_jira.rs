@@ -0,0 +1,142 @@
+//! Finds Jira issue keys like `PROJ-123` mentioned in GitHub pull requests,
+//! commits and branches, to comment on the matching Jira issues.
+//!
+//! eg.
+//! ```rust
+//! use flows_connector_dsi::github_jira::IssueKeyScanner;
+//!
+//! let scanner = IssueKeyScanner::new().allow("PROJ");
+//!
+//! assert_eq!(
+//!     scanner.scan("PROJ-12: fix login, see OTHER-3 and PROJ-7"),
+//!     vec!["PROJ-12", "PROJ-7"]
+//! );
+//! ```
+
+use crate::{
+    github::{Commit, PullRequest},
+    jira::outbound::{self, OutboundData},
+};
+
+/// Scans text for Jira issue keys, optionally limited to some projects.
+///
+/// A key is an uppercase project key of at least two characters, a hyphen and
+/// the issue number, not preceded or followed by a letter, digit or underscore.
+/// Limit the projects with [`IssueKeyScanner::allow`] to skip look-alikes like `UTF-8`.
+#[derive(Debug, Clone, Default)]
+pub struct IssueKeyScanner {
+    projects: Vec<String>,
+}
+
+#[inline]
+fn is_word(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+impl IssueKeyScanner {
+    /// A scanner matching the keys of any project.
+    pub fn new() -> IssueKeyScanner {
+        IssueKeyScanner::default()
+    }
+
+    /// Only match the keys of the project, can be called multiple times.
+    pub fn allow<S: Into<String>>(mut self, project_key: S) -> IssueKeyScanner {
+        self.projects.push(project_key.into());
+        self
+    }
+
+    fn is_allowed(&self, project_key: &str) -> bool {
+        self.projects.is_empty() || self.projects.iter().any(|p| p == project_key)
+    }
+
+    /// The issue key starting at `start`, and the index right after it.
+    fn key_at<'t>(&self, text: &'t str, start: usize) -> Option<(&'t str, usize)> {
+        let bytes = text.as_bytes();
+        if !bytes[start].is_ascii_uppercase() || (start > 0 && is_word(bytes[start - 1])) {
+            return None;
+        }
+
+        let project_end = start
+            + bytes[start..]
+                .iter()
+                .take_while(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || **b == b'_')
+                .count();
+        if project_end - start < 2 || bytes.get(project_end) != Some(&b'-') {
+            return None;
+        }
+
+        let number_start = project_end + 1;
+        let end = number_start
+            + bytes[number_start..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+        if end == number_start
+            || bytes[number_start] == b'0'
+            || bytes.get(end).is_some_and(|b| is_word(*b))
+        {
+            return None;
+        }
+
+        self.is_allowed(&text[start..project_end])
+            .then(|| (&text[start..end], end))
+    }
+
+    /// The issue keys in the text, without duplicates and in order of appearance.
+    pub fn scan(&self, text: &str) -> Vec<String> {
+        let mut keys = vec![];
+        self.scan_into(text, &mut keys);
+        keys
+    }
+
+    fn scan_into(&self, text: &str, keys: &mut Vec<String>) {
+        let mut i = 0;
+        while i < text.len() {
+            match self.key_at(text, i) {
+                Some((key, end)) => {
+                    if !keys.iter().any(|k| k == key) {
+                        keys.push(key.to_string());
+                    }
+                    i = end;
+                }
+                None => i += 1,
+            }
+        }
+    }
+
+    /// The issue keys in the branch name of a ref like `refs/heads/PROJ-123-fix-login`.
+    pub fn scan_ref(&self, git_ref: &str) -> Vec<String> {
+        self.scan(git_ref.strip_prefix("refs/heads/").unwrap_or(git_ref))
+    }
+
+    /// The issue keys in the title, body and head branch of the pull request.
+    pub fn scan_pull_request(&self, pr: &PullRequest) -> Vec<String> {
+        let mut keys = vec![];
+        self.scan_into(&pr.title, &mut keys);
+        self.scan_into(pr.body.as_deref().unwrap_or_default(), &mut keys);
+        self.scan_into(&pr.head.r#ref, &mut keys);
+        keys
+    }
+
+    /// The issue keys in the commit message.
+    pub fn scan_commit(&self, commit: &Commit) -> Vec<String> {
+        self.scan(&commit.message)
+    }
+
+    /// The issue keys in the messages of the commits.
+    pub fn scan_commits(&self, commits: &[Commit]) -> Vec<String> {
+        let mut keys = vec![];
+        for commit in commits {
+            self.scan_into(&commit.message, &mut keys);
+        }
+        keys
+    }
+}
+
+/// Comment on each of the issues, build the payloads with
+/// [`OutboundData::build`](crate::jira::outbound::OutboundData::build).
+pub fn comment_on_issues<'a, S: AsRef<str>>(keys: &[S], comment: &str) -> Vec<OutboundData<'a>> {
+    keys.iter()
+        .map(|key| outbound::modify_issue(key.as_ref()).comment(comment))
+        .collect()
+}
@@ -1,10 +1,19 @@
-use serde::Deserialize;
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use serde_json::Value;
+
+pub mod adf;
+pub mod jql;
+
+use adf::Body;
 
 #[derive(Deserialize, Debug)]
 pub struct ChangeLogItem {
     pub field: String,
     #[serde(rename = "fieldId")]
-    pub field_id: String,
+    pub field_id: Option<String>,
     pub fieldtype: String,
     pub from: Option<String>,
     #[serde(rename = "fromString")]
@@ -19,31 +28,196 @@ pub struct ChangeLog {
     pub items: Vec<ChangeLogItem>,
 }
 
+/// A well-known field that can appear in a changelog item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeLogField {
+    Assignee,
+    Components,
+    Description,
+    DueDate,
+    FixVersions,
+    IssueType,
+    Labels,
+    Parent,
+    Priority,
+    Reporter,
+    Resolution,
+    Sprint,
+    Status,
+    Summary,
+    /// Any other field, by id or display name.
+    Other(String),
+}
+
+impl From<&str> for ChangeLogField {
+    /// Accepts both the field id (`fixVersions`) and the display
+    /// name Jira puts in `field` (`Fix Version`).
+    fn from(s: &str) -> Self {
+        match s.to_ascii_lowercase().as_str() {
+            "assignee" => ChangeLogField::Assignee,
+            "components" | "component" => ChangeLogField::Components,
+            "description" => ChangeLogField::Description,
+            "duedate" => ChangeLogField::DueDate,
+            "fixversions" | "fix version" => ChangeLogField::FixVersions,
+            "issuetype" => ChangeLogField::IssueType,
+            "labels" => ChangeLogField::Labels,
+            "parent" | "issueparentassociation" => ChangeLogField::Parent,
+            "priority" => ChangeLogField::Priority,
+            "reporter" => ChangeLogField::Reporter,
+            "resolution" => ChangeLogField::Resolution,
+            "sprint" => ChangeLogField::Sprint,
+            "status" => ChangeLogField::Status,
+            "summary" => ChangeLogField::Summary,
+            _ => ChangeLogField::Other(s.to_string()),
+        }
+    }
+}
+
+impl ChangeLogItem {
+    /// The well-known field this item changes.
+    pub fn kind(&self) -> ChangeLogField {
+        // Custom fields such as Sprint only have a recognisable name in `field`.
+        match self.field_id.as_deref().map(ChangeLogField::from) {
+            Some(ChangeLogField::Other(_)) | None => ChangeLogField::from(self.field.as_str()),
+            Some(f) => f,
+        }
+    }
+
+    /// Whether this item changes `field`, given as a [`ChangeLogField`],
+    /// a field id or a display name.
+    pub fn is<F: Into<ChangeLogField>>(&self, field: F) -> bool {
+        match field.into() {
+            ChangeLogField::Other(name) => {
+                self.field_id.as_deref() == Some(name.as_str()) || self.field == name
+            }
+            f => self.kind() == f,
+        }
+    }
+}
+
+impl ChangeLog {
+    /// The first item changing `field`.
+    pub fn field_change<F: Into<ChangeLogField>>(&self, field: F) -> Option<&ChangeLogItem> {
+        let field = field.into();
+        self.items.iter().find(|i| i.is(field.clone()))
+    }
+
+    /// The status names before and after the change.
+    pub fn status_change(&self) -> Option<(&str, &str)> {
+        self.field_change(ChangeLogField::Status).map(|i| {
+            (
+                i.from_string.as_deref().unwrap_or_default(),
+                i.to_string.as_deref().unwrap_or_default(),
+            )
+        })
+    }
+
+    /// The assignee account ids before and after the change,
+    /// `None` meaning unassigned.
+    pub fn assignee_change(&self) -> Option<(Option<&str>, Option<&str>)> {
+        self.field_change(ChangeLogField::Assignee)
+            .map(|i| (i.from.as_deref(), i.to.as_deref()))
+    }
+
+    /// Labels present after the change but not before.
+    pub fn labels_added(&self) -> Vec<&str> {
+        self.labels_diff(|i| (i.to_string.as_deref(), i.from_string.as_deref()))
+    }
+
+    /// Labels present before the change but not after.
+    pub fn labels_removed(&self) -> Vec<&str> {
+        self.labels_diff(|i| (i.from_string.as_deref(), i.to_string.as_deref()))
+    }
+
+    fn labels_diff<'a>(
+        &'a self,
+        sides: impl Fn(&'a ChangeLogItem) -> (Option<&'a str>, Option<&'a str>),
+    ) -> Vec<&'a str> {
+        let (these, others) = match self.field_change(ChangeLogField::Labels) {
+            Some(item) => sides(item),
+            None => return vec![],
+        };
+        let others = others
+            .unwrap_or_default()
+            .split_whitespace()
+            .collect::<Vec<_>>();
+        these
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter(|l| !others.contains(l))
+            .collect()
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct AvatarUrls {
     #[serde(rename = "16x16")]
-    _16x16: String,
+    pub x16: Option<String>,
     #[serde(rename = "24x24")]
-    _24x24: String,
+    pub x24: Option<String>,
     #[serde(rename = "32x32")]
-    _32x32: String,
+    pub x32: Option<String>,
     #[serde(rename = "48x48")]
-    _48x48: String,
+    pub x48: Option<String>,
 }
 
+impl AvatarUrls {
+    /// The URL of the smallest avatar at least `size` pixels wide,
+    /// or the largest one if none is big enough.
+    pub fn for_size(&self, size: u32) -> Option<&str> {
+        let avatars = [
+            (16, &self.x16),
+            (24, &self.x24),
+            (32, &self.x32),
+            (48, &self.x48),
+        ];
+        let mut available = avatars
+            .iter()
+            .filter_map(|(px, url)| url.as_deref().map(|url| (*px, url)));
+
+        available
+            .clone()
+            .find(|(px, _)| *px >= size)
+            .or_else(|| available.next_back())
+            .map(|(_, url)| url)
+    }
+}
+
+/// A Jira user, app users and users restricted by their privacy settings
+/// omit some of the fields.
 #[derive(Deserialize, Debug)]
 pub struct User {
+    #[serde(rename = "accountId")]
+    pub account_id: Option<String>,
+    /// One of `atlassian`, `app` and `customer`.
     #[serde(rename = "accountType")]
-    pub account_type: String,
-    pub active: bool,
+    pub account_type: Option<String>,
+    pub active: Option<bool>,
     #[serde(rename = "avatarUrls")]
-    pub avatar_urls: AvatarUrls,
+    pub avatar_urls: Option<AvatarUrls>,
     #[serde(rename = "displayName")]
-    pub display_name: String,
+    pub display_name: Option<String>,
+    #[serde(rename = "emailAddress")]
+    pub email_address: Option<String>,
     #[serde(rename = "self")]
-    pub _self: String,
+    pub _self: Option<String>,
     #[serde(rename = "timeZone")]
-    pub time_zone: String,
+    pub time_zone: Option<String>,
+}
+
+impl User {
+    /// The display name, falling back to the account id.
+    pub fn name(&self) -> &str {
+        self.display_name
+            .as_deref()
+            .or(self.account_id.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Whether the user is an app like Automation for Jira.
+    pub fn is_app(&self) -> bool {
+        self.account_type.as_deref() == Some("app")
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -68,13 +242,109 @@ pub struct Progress {
 
 #[derive(Deserialize, Debug)]
 pub struct IssueType {
+    pub id: String,
     pub description: String,
     pub name: String,
+    #[serde(rename = "iconUrl")]
+    pub icon_url: Option<String>,
+    #[serde(rename = "hierarchyLevel")]
+    pub hierarchy_level: Option<i32>,
     #[serde(rename = "self")]
     pub _self: String,
     pub subtask: bool,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct StatusCategory {
+    pub id: u64,
+    /// One of `new`, `indeterminate` and `done`.
+    pub key: String,
+    pub name: String,
+    #[serde(rename = "colorName")]
+    pub color_name: String,
+    #[serde(rename = "self")]
+    pub _self: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Status {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "iconUrl")]
+    pub icon_url: Option<String>,
+    #[serde(rename = "statusCategory")]
+    pub status_category: Option<StatusCategory>,
+    #[serde(rename = "self")]
+    pub _self: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Priority {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "iconUrl")]
+    pub icon_url: Option<String>,
+    #[serde(rename = "self")]
+    pub _self: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Resolution {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "self")]
+    pub _self: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Component {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "self")]
+    pub _self: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Version {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default = "bool::default")]
+    pub archived: bool,
+    #[serde(default = "bool::default")]
+    pub released: bool,
+    #[serde(rename = "releaseDate")]
+    pub release_date: Option<String>,
+    #[serde(rename = "startDate")]
+    pub start_date: Option<String>,
+    #[serde(rename = "projectId")]
+    pub project_id: Option<u64>,
+    #[serde(rename = "self")]
+    pub _self: Option<String>,
+}
+
+/// The fields of an issue referenced by another issue.
+#[derive(Deserialize, Debug)]
+pub struct IssueRefField {
+    pub summary: Option<String>,
+    pub status: Option<Status>,
+    pub priority: Option<Priority>,
+    pub issuetype: Option<IssueType>,
+}
+
+/// An issue referenced by another issue, like its parent or subtasks.
+#[derive(Deserialize, Debug)]
+pub struct IssueRef {
+    pub id: String,
+    pub key: String,
+    #[serde(rename = "self")]
+    pub _self: String,
+    pub fields: Option<IssueRefField>,
+}
+
 #[derive(Deserialize, Debug)]
 pub struct Votes {
     #[serde(rename = "hasVoted")]
@@ -88,18 +358,164 @@ pub struct Votes {
 pub struct IssueField {
     pub aggregateprogress: Option<Progress>,
     pub assignee: Option<User>,
+    #[serde(default = "Vec::new")]
+    pub components: Vec<Component>,
     pub created: Option<String>,
     pub creator: Option<User>,
+    pub description: Option<Body>,
+    pub duedate: Option<String>,
+    #[serde(default = "Vec::new", rename = "fixVersions")]
+    pub fix_versions: Vec<Version>,
+    pub issuetype: Option<IssueType>,
     #[serde(default = "Vec::new")]
     pub labels: Vec<String>,
     #[serde(rename = "lastViewed")]
     pub last_viewed: Option<String>,
+    pub parent: Option<IssueRef>,
+    pub priority: Option<Priority>,
     pub progress: Option<Progress>,
     pub project: Project,
     pub reporter: Option<User>,
+    pub resolution: Option<Resolution>,
+    pub resolutiondate: Option<String>,
+    pub status: Option<Status>,
+    #[serde(default = "Vec::new")]
+    pub subtasks: Vec<IssueRef>,
     pub summary: String,
+    /// Remaining estimate in seconds.
+    pub timeestimate: Option<u64>,
+    /// Original estimate in seconds.
+    pub timeoriginalestimate: Option<u64>,
+    /// Time logged in seconds.
+    pub timespent: Option<u64>,
+    pub timetracking: Option<TimeTracking>,
     pub updated: Option<String>,
     pub votes: Option<Votes>,
+
+    /// The other fields, including the custom fields like `customfield_10020`.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl IssueField {
+    /// Gets the custom field by its id and deserializes into `T`,
+    /// `null` values are treated as missing.
+    ///
+    /// `T` can be `f64` for number fields, `String` for text fields,
+    /// [`CustomFieldOption`] for select lists, `Vec<CustomFieldOption>` for
+    /// multi-select lists, [`User`] for user pickers, `Vec<Sprint>` for sprints
+    /// and [`DateField`] for date pickers.
+    ///
+    /// eg.
+    /// ```rust
+    /// # use flows_connector_dsi::jira::{IssueField, Sprint};
+    /// # fn sprints(fields: &IssueField) -> Result<Vec<Sprint>, String> {
+    /// let sprints: Vec<Sprint> = fields.get_custom_field("customfield_10020")?;
+    /// # Ok(sprints)
+    /// # }
+    /// ```
+    pub fn get_custom_field<T: DeserializeOwned>(&self, id: &str) -> Result<T, String> {
+        match self.extra.get(id) {
+            None | Some(Value::Null) => Err(format!("Missing {}", id)),
+            Some(v) => serde_json::from_value(v.clone()).map_err(|e| format!("{}: {}", id, e)),
+        }
+    }
+
+    /// Gets the custom field by its friendly name registered in `names`,
+    /// or by its id if the name is not registered.
+    #[inline]
+    pub fn get_custom_field_named<T: DeserializeOwned>(
+        &self,
+        names: &CustomFieldNames,
+        name: &str,
+    ) -> Result<T, String> {
+        self.get_custom_field(names.id(name))
+    }
+}
+
+/// Friendly names of custom field ids, which differ between Jira instances.
+///
+/// eg.
+/// ```rust
+/// use flows_connector_dsi::jira::CustomFieldNames;
+///
+/// let names = CustomFieldNames::new()
+///     .register("sprint", "customfield_10020")
+///     .register("story_points", "customfield_10016");
+///
+/// assert_eq!(names.id("sprint"), "customfield_10020");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct CustomFieldNames {
+    ids: HashMap<String, String>,
+}
+
+impl CustomFieldNames {
+    pub fn new() -> CustomFieldNames {
+        CustomFieldNames::default()
+    }
+
+    /// Register the friendly name of the custom field id.
+    pub fn register<N: Into<String>, I: Into<String>>(
+        mut self,
+        name: N,
+        id: I,
+    ) -> CustomFieldNames {
+        self.ids.insert(name.into(), id.into());
+        self
+    }
+
+    /// Gets the custom field id of the name, the name itself if not registered.
+    pub fn id<'a>(&'a self, name: &'a str) -> &'a str {
+        self.ids.get(name).map(String::as_str).unwrap_or(name)
+    }
+}
+
+/// The value of a select list or radio button custom field.
+#[derive(Deserialize, Debug)]
+pub struct CustomFieldOption {
+    pub id: String,
+    pub value: String,
+    #[serde(rename = "self")]
+    pub _self: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Sprint {
+    pub id: u64,
+    pub name: String,
+    /// One of `future`, `active` and `closed`.
+    pub state: String,
+    #[serde(rename = "boardId")]
+    pub board_id: Option<u64>,
+    /// The board the sprint was created on, sent by sprint webhook events.
+    #[serde(rename = "originBoardId")]
+    pub origin_board_id: Option<u64>,
+    pub goal: Option<String>,
+    #[serde(rename = "startDate")]
+    pub start_date: Option<String>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<String>,
+    #[serde(rename = "completeDate")]
+    pub complete_date: Option<String>,
+    /// Only present in sprint webhook events.
+    #[serde(rename = "self")]
+    pub _self: Option<String>,
+}
+
+/// The value of a date picker custom field, like `2022-10-31`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateField(pub NaiveDate);
+
+impl<'de> Deserialize<'de> for DateField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        // Date time pickers carry the time after the date, like `2022-10-11T16:22:18.994+0800`.
+        NaiveDate::parse_from_str(s.get(..10).unwrap_or(&s), "%Y-%m-%d")
+            .map(DateField)
+            .map_err(serde::de::Error::custom)
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -114,7 +530,7 @@ pub struct Issue {
 #[derive(Deserialize, Debug)]
 pub struct Comment {
     pub author: User,
-    pub body: String,
+    pub body: Body,
     pub created: String,
     #[serde(rename = "self")]
     pub _self: String,
@@ -123,14 +539,409 @@ pub struct Comment {
     pub updated: String,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct IssueLinkType {
+    pub id: u64,
+    pub name: String,
+    /// Like `is blocked by`.
+    #[serde(rename = "inwardName")]
+    pub inward_name: String,
+    /// Like `blocks`.
+    #[serde(rename = "outwardName")]
+    pub outward_name: String,
+    #[serde(rename = "isSubTaskLinkType", default = "bool::default")]
+    pub is_subtask_link_type: bool,
+    #[serde(rename = "isSystemLinkType", default = "bool::default")]
+    pub is_system_link_type: bool,
+}
+
+/// A link between two issues, the source issue `outward_name` the destination
+/// issue, like "HT-1 blocks HT-2".
+#[derive(Deserialize, Debug)]
+pub struct IssueLink {
+    pub id: u64,
+    #[serde(rename = "sourceIssueId")]
+    pub source_issue_id: u64,
+    #[serde(rename = "destinationIssueId")]
+    pub destination_issue_id: u64,
+    #[serde(rename = "issueLinkType")]
+    pub issue_link_type: IssueLinkType,
+    #[serde(rename = "systemLink", default = "bool::default")]
+    pub system_link: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TimeTracking {
+    #[serde(rename = "originalEstimate")]
+    pub original_estimate: Option<String>,
+    #[serde(rename = "originalEstimateSeconds")]
+    pub original_estimate_seconds: Option<u64>,
+    #[serde(rename = "remainingEstimate")]
+    pub remaining_estimate: Option<String>,
+    #[serde(rename = "remainingEstimateSeconds")]
+    pub remaining_estimate_seconds: Option<u64>,
+    #[serde(rename = "timeSpent")]
+    pub time_spent: Option<String>,
+    #[serde(rename = "timeSpentSeconds")]
+    pub time_spent_seconds: Option<u64>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Worklog {
+    pub author: User,
+    pub comment: Option<Body>,
+    pub created: String,
+    pub id: String,
+    #[serde(rename = "issueId")]
+    pub issue_id: String,
+    #[serde(rename = "self")]
+    pub _self: String,
+    pub started: String,
+    #[serde(rename = "timeSpent")]
+    pub time_spent: String,
+    #[serde(rename = "timeSpentSeconds")]
+    pub time_spent_seconds: u64,
+    #[serde(rename = "updateAuthor")]
+    pub update_author: Option<User>,
+    pub updated: String,
+}
+
+/// The working time used by Jira to convert days and weeks in durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkingTime {
+    pub hours_per_day: u64,
+    pub days_per_week: u64,
+}
+
+impl Default for WorkingTime {
+    /// Jira's default of 8 hours a day, 5 days a week.
+    fn default() -> Self {
+        Self {
+            hours_per_day: 8,
+            days_per_week: 5,
+        }
+    }
+}
+
+impl WorkingTime {
+    fn unit_seconds(&self, unit: char) -> Option<u64> {
+        match unit {
+            'w' => self
+                .days_per_week
+                .checked_mul(self.hours_per_day)?
+                .checked_mul(3600),
+            'd' => self.hours_per_day.checked_mul(3600),
+            'h' => Some(3600),
+            'm' => Some(60),
+            _ => None,
+        }
+    }
+
+    /// Parses a Jira duration like `1w 2d 3h 30m` or `1.5h` into seconds,
+    /// fractions are rounded to the second.
+    pub fn parse_duration(&self, duration: &str) -> Result<u64, String> {
+        let mut seconds: u64 = 0;
+        let mut chars = duration.trim().chars().peekable();
+
+        if chars.peek().is_none() {
+            return Err("Empty duration".to_string());
+        }
+
+        while chars.peek().is_some() {
+            let mut number = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_digit() || *c == '.') {
+                number.push(c);
+            }
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+            let (whole, fraction) = number.split_once('.').unwrap_or((&number, ""));
+            if whole.is_empty() && fraction.is_empty() {
+                return Err(format!("Invalid duration: {}", duration));
+            }
+            let unit = chars
+                .next()
+                .and_then(|u| self.unit_seconds(u.to_ascii_lowercase()))
+                .ok_or(format!("Invalid duration: {}", duration))?;
+
+            let whole = match whole {
+                "" => 0,
+                whole => whole
+                    .parse::<u64>()
+                    .map_err(|_| format!("Duration too long: {}", duration))?,
+            };
+            // The fraction is below one, so its seconds are at most one unit.
+            let fraction = format!("0.{}", fraction)
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid duration: {}", duration))?;
+            let fraction = (fraction * unit as f64).round() as u64;
+
+            seconds = whole
+                .checked_mul(unit)
+                .and_then(|s| s.checked_add(fraction))
+                .and_then(|s| s.checked_add(seconds))
+                .ok_or(format!("Duration too long: {}", duration))?;
+
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        }
+
+        Ok(seconds)
+    }
+
+    /// Formats seconds as a Jira duration like `1w 2d 3h 30m`,
+    /// leftover seconds are dropped.
+    pub fn format_duration(&self, seconds: u64) -> String {
+        let mut rest = seconds;
+        let parts = ['w', 'd', 'h', 'm']
+            .into_iter()
+            .filter_map(|unit| {
+                let size = self.unit_seconds(unit)?;
+                if size == 0 {
+                    return None;
+                }
+                let n = rest / size;
+                rest %= size;
+                (n > 0).then(|| format!("{}{}", n, unit))
+            })
+            .collect::<Vec<_>>();
+
+        if parts.is_empty() {
+            "0m".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+}
+
+/// The `webhookEvent` of a Jira webhook payload.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "String")]
+pub enum JiraEvent {
+    IssueCreated,
+    IssueUpdated,
+    IssueDeleted,
+    CommentCreated,
+    CommentUpdated,
+    CommentDeleted,
+    WorklogCreated,
+    WorklogUpdated,
+    WorklogDeleted,
+    IssueLinkCreated,
+    IssueLinkDeleted,
+    SprintCreated,
+    SprintUpdated,
+    SprintDeleted,
+    SprintStarted,
+    SprintClosed,
+    VersionCreated,
+    VersionUpdated,
+    VersionDeleted,
+    VersionReleased,
+    VersionUnreleased,
+    VersionMoved,
+    VersionMerged,
+    ProjectCreated,
+    ProjectUpdated,
+    ProjectDeleted,
+    UserCreated,
+    UserUpdated,
+    UserDeleted,
+    /// Any other value.
+    Unknown(String),
+}
+
+impl From<String> for JiraEvent {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "jira:issue_created" => JiraEvent::IssueCreated,
+            "jira:issue_updated" => JiraEvent::IssueUpdated,
+            "jira:issue_deleted" => JiraEvent::IssueDeleted,
+            "comment_created" => JiraEvent::CommentCreated,
+            "comment_updated" => JiraEvent::CommentUpdated,
+            "comment_deleted" => JiraEvent::CommentDeleted,
+            "worklog_created" => JiraEvent::WorklogCreated,
+            "worklog_updated" => JiraEvent::WorklogUpdated,
+            "worklog_deleted" => JiraEvent::WorklogDeleted,
+            "issuelink_created" => JiraEvent::IssueLinkCreated,
+            "issuelink_deleted" => JiraEvent::IssueLinkDeleted,
+            "sprint_created" => JiraEvent::SprintCreated,
+            "sprint_updated" => JiraEvent::SprintUpdated,
+            "sprint_deleted" => JiraEvent::SprintDeleted,
+            "sprint_started" => JiraEvent::SprintStarted,
+            "sprint_closed" => JiraEvent::SprintClosed,
+            "jira:version_created" => JiraEvent::VersionCreated,
+            "jira:version_updated" => JiraEvent::VersionUpdated,
+            "jira:version_deleted" => JiraEvent::VersionDeleted,
+            "jira:version_released" => JiraEvent::VersionReleased,
+            "jira:version_unreleased" => JiraEvent::VersionUnreleased,
+            "jira:version_moved" => JiraEvent::VersionMoved,
+            "jira:version_merged" => JiraEvent::VersionMerged,
+            "project_created" => JiraEvent::ProjectCreated,
+            "project_updated" => JiraEvent::ProjectUpdated,
+            "project_deleted" => JiraEvent::ProjectDeleted,
+            "user_created" => JiraEvent::UserCreated,
+            "user_updated" => JiraEvent::UserUpdated,
+            "user_deleted" => JiraEvent::UserDeleted,
+            _ => JiraEvent::Unknown(s),
+        }
+    }
+}
+
+impl JiraEvent {
+    /// The raw value sent by Jira.
+    pub fn as_str(&self) -> &str {
+        match self {
+            JiraEvent::IssueCreated => "jira:issue_created",
+            JiraEvent::IssueUpdated => "jira:issue_updated",
+            JiraEvent::IssueDeleted => "jira:issue_deleted",
+            JiraEvent::CommentCreated => "comment_created",
+            JiraEvent::CommentUpdated => "comment_updated",
+            JiraEvent::CommentDeleted => "comment_deleted",
+            JiraEvent::WorklogCreated => "worklog_created",
+            JiraEvent::WorklogUpdated => "worklog_updated",
+            JiraEvent::WorklogDeleted => "worklog_deleted",
+            JiraEvent::IssueLinkCreated => "issuelink_created",
+            JiraEvent::IssueLinkDeleted => "issuelink_deleted",
+            JiraEvent::SprintCreated => "sprint_created",
+            JiraEvent::SprintUpdated => "sprint_updated",
+            JiraEvent::SprintDeleted => "sprint_deleted",
+            JiraEvent::SprintStarted => "sprint_started",
+            JiraEvent::SprintClosed => "sprint_closed",
+            JiraEvent::VersionCreated => "jira:version_created",
+            JiraEvent::VersionUpdated => "jira:version_updated",
+            JiraEvent::VersionDeleted => "jira:version_deleted",
+            JiraEvent::VersionReleased => "jira:version_released",
+            JiraEvent::VersionUnreleased => "jira:version_unreleased",
+            JiraEvent::VersionMoved => "jira:version_moved",
+            JiraEvent::VersionMerged => "jira:version_merged",
+            JiraEvent::ProjectCreated => "project_created",
+            JiraEvent::ProjectUpdated => "project_updated",
+            JiraEvent::ProjectDeleted => "project_deleted",
+            JiraEvent::UserCreated => "user_created",
+            JiraEvent::UserUpdated => "user_updated",
+            JiraEvent::UserDeleted => "user_deleted",
+            JiraEvent::Unknown(s) => s,
+        }
+    }
+
+    /// Whether the event is about an issue, including its comments, worklogs and links.
+    pub fn is_issue_event(&self) -> bool {
+        matches!(
+            self,
+            JiraEvent::IssueCreated
+                | JiraEvent::IssueUpdated
+                | JiraEvent::IssueDeleted
+                | JiraEvent::CommentCreated
+                | JiraEvent::CommentUpdated
+                | JiraEvent::CommentDeleted
+                | JiraEvent::WorklogCreated
+                | JiraEvent::WorklogUpdated
+                | JiraEvent::WorklogDeleted
+                | JiraEvent::IssueLinkCreated
+                | JiraEvent::IssueLinkDeleted
+        )
+    }
+}
+
+impl std::fmt::Display for JiraEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The `issue_event_type_name` of a Jira issue event, which tells
+/// what happened to the issue in more detail than `webhookEvent`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "String")]
+pub enum IssueEventType {
+    IssueCreated,
+    IssueUpdated,
+    IssueGeneric,
+    IssueAssigned,
+    IssueCommented,
+    IssueCommentEdited,
+    IssueCommentDeleted,
+    IssueResolved,
+    IssueClosed,
+    IssueReopened,
+    IssueMoved,
+    IssueWorkStarted,
+    IssueWorkStopped,
+    IssueWorkLogged,
+    IssueWorklogUpdated,
+    IssueWorklogDeleted,
+    /// Any other value.
+    Unknown(String),
+}
+
+impl From<String> for IssueEventType {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "issue_created" => IssueEventType::IssueCreated,
+            "issue_updated" => IssueEventType::IssueUpdated,
+            "issue_generic" => IssueEventType::IssueGeneric,
+            "issue_assigned" => IssueEventType::IssueAssigned,
+            "issue_commented" => IssueEventType::IssueCommented,
+            "issue_comment_edited" => IssueEventType::IssueCommentEdited,
+            "issue_comment_deleted" => IssueEventType::IssueCommentDeleted,
+            "issue_resolved" => IssueEventType::IssueResolved,
+            "issue_closed" => IssueEventType::IssueClosed,
+            "issue_reopened" => IssueEventType::IssueReopened,
+            "issue_moved" => IssueEventType::IssueMoved,
+            "issue_work_started" => IssueEventType::IssueWorkStarted,
+            "issue_work_stopped" => IssueEventType::IssueWorkStopped,
+            "issue_work_logged" => IssueEventType::IssueWorkLogged,
+            "issue_worklog_updated" => IssueEventType::IssueWorklogUpdated,
+            "issue_worklog_deleted" => IssueEventType::IssueWorklogDeleted,
+            _ => IssueEventType::Unknown(s),
+        }
+    }
+}
+
+impl IssueEventType {
+    /// The raw value sent by Jira.
+    pub fn as_str(&self) -> &str {
+        match self {
+            IssueEventType::IssueCreated => "issue_created",
+            IssueEventType::IssueUpdated => "issue_updated",
+            IssueEventType::IssueGeneric => "issue_generic",
+            IssueEventType::IssueAssigned => "issue_assigned",
+            IssueEventType::IssueCommented => "issue_commented",
+            IssueEventType::IssueCommentEdited => "issue_comment_edited",
+            IssueEventType::IssueCommentDeleted => "issue_comment_deleted",
+            IssueEventType::IssueResolved => "issue_resolved",
+            IssueEventType::IssueClosed => "issue_closed",
+            IssueEventType::IssueReopened => "issue_reopened",
+            IssueEventType::IssueMoved => "issue_moved",
+            IssueEventType::IssueWorkStarted => "issue_work_started",
+            IssueEventType::IssueWorkStopped => "issue_work_stopped",
+            IssueEventType::IssueWorkLogged => "issue_work_logged",
+            IssueEventType::IssueWorklogUpdated => "issue_worklog_updated",
+            IssueEventType::IssueWorklogDeleted => "issue_worklog_deleted",
+            IssueEventType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for IssueEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct InboundData {
     pub changelog: Option<ChangeLog>,
     pub issue: Option<Issue>,
     pub comment: Option<Comment>,
+    pub worklog: Option<Worklog>,
+    #[serde(rename = "issueLink")]
+    pub issue_link: Option<IssueLink>,
+    pub sprint: Option<Sprint>,
+    pub version: Option<Version>,
     pub timestamp: u64,
     #[serde(rename = "webhookEvent")]
-    pub webhook_event: String,
+    pub webhook_event: JiraEvent,
+    pub issue_event_type_name: Option<IssueEventType>,
 }
 
 impl InboundData {
@@ -150,6 +961,35 @@ impl InboundData {
     pub fn get_comment(&self) -> Result<&Comment, String> {
         self.comment.as_ref().ok_or("Missing comment".to_string())
     }
+
+    #[inline]
+    pub fn get_worklog(&self) -> Result<&Worklog, String> {
+        self.worklog.as_ref().ok_or("Missing worklog".to_string())
+    }
+
+    #[inline]
+    pub fn get_issue_link(&self) -> Result<&IssueLink, String> {
+        self.issue_link
+            .as_ref()
+            .ok_or("Missing issueLink".to_string())
+    }
+
+    #[inline]
+    pub fn get_sprint(&self) -> Result<&Sprint, String> {
+        self.sprint.as_ref().ok_or("Missing sprint".to_string())
+    }
+
+    #[inline]
+    pub fn get_version(&self) -> Result<&Version, String> {
+        self.version.as_ref().ok_or("Missing version".to_string())
+    }
+
+    #[inline]
+    pub fn get_issue_event_type(&self) -> Result<&IssueEventType, String> {
+        self.issue_event_type_name
+            .as_ref()
+            .ok_or("Missing issue_event_type_name".to_string())
+    }
 }
 
 /// Deserialize the Jira Webhook Payload into `InboundData`.
@@ -168,6 +1008,8 @@ pub mod outbound {
 
     use serde_json::{json, Value};
 
+    use super::{adf::Document, jql::Query};
+
     pub struct OutboundData<'a> {
         inner: HashMap<&'a str, Value>,
     }
@@ -179,24 +1021,161 @@ pub mod outbound {
             self
         }
 
-        /// Set description.
+        /// Set description, replacing one set by [`OutboundData::description_adf`].
         pub fn description<S: Into<String>>(mut self, description: S) -> OutboundData<'a> {
             self.inner.insert("description", json!(description.into()));
             self
         }
 
+        /// Set description in Atlassian Document Format,
+        /// use [`Document::from_markdown`](super::adf::Document::from_markdown)
+        /// to build it from Markdown. Replaces a description set by [`OutboundData::description`].
+        pub fn description_adf(mut self, description: &Document) -> OutboundData<'a> {
+            self.inner.insert("description", json!(description));
+            self
+        }
+
+        /// Add a comment in Atlassian Document Format, only used on modify issue and log work.
+        /// Replaces a comment set by [`OutboundData::comment`], only one comment is sent.
+        pub fn comment_adf(mut self, comment: &Document) -> OutboundData<'a> {
+            self.inner.insert("comment", json!(comment));
+            self
+        }
+
+        /// Add a plain text comment, only used on modify issue and log work.
+        /// Replaces a comment set by [`OutboundData::comment_adf`], only one comment is sent.
+        pub fn comment<S: Into<String>>(mut self, comment: S) -> OutboundData<'a> {
+            self.inner.insert("comment", json!(comment.into()));
+            self
+        }
+
+        /// Set when the work started like `2022-10-11T09:00:00.000+0000`,
+        /// only used on log work.
+        pub fn started<S: Into<String>>(mut self, started: S) -> OutboundData<'a> {
+            self.inner.insert("started", json!(started.into()));
+            self
+        }
+
+        /// Set the 16x16 icon shown next to the link, only used on create remote link.
+        pub fn icon<U: Into<String>, T: Into<String>>(
+            mut self,
+            url: U,
+            title: T,
+        ) -> OutboundData<'a> {
+            self.inner.insert("icon_url", json!(url.into()));
+            self.inner.insert("icon_title", json!(title.into()));
+            self
+        }
+
+        /// Set an id unique to the linked page, creating the same remote link
+        /// again updates it instead, only used on create remote link.
+        pub fn global_id<S: Into<String>>(mut self, global_id: S) -> OutboundData<'a> {
+            self.inner.insert("global_id", json!(global_id.into()));
+            self
+        }
+
+        /// Set the maximum number of issues returned, only used on search.
+        pub fn max_results(mut self, max_results: u32) -> OutboundData<'a> {
+            self.inner.insert("max_results", json!(max_results));
+            self
+        }
+
+        /// Set the fields returned for each issue, only used on search.
+        pub fn fields<S: Into<String>>(mut self, fields: Vec<S>) -> OutboundData<'a> {
+            let fields = fields.into_iter().map(Into::into).collect::<Vec<String>>();
+            self.inner.insert("fields", json!(fields));
+            self
+        }
+
         /// Set transition, only used on modify issue.
         pub fn transition<S: Into<String>>(mut self, transition: S) -> OutboundData<'a> {
             self.inner.insert("transition", json!(transition.into()));
             self
         }
 
+        /// Set the assignee by account id.
+        pub fn assignee<S: Into<String>>(mut self, account_id: S) -> OutboundData<'a> {
+            self.inner.insert("assignee", json!(account_id.into()));
+            self
+        }
+
+        /// Remove the assignee.
+        pub fn unassign(mut self) -> OutboundData<'a> {
+            self.inner.insert("assignee", Value::Null);
+            self
+        }
+
+        /// Add labels, keeping the existing ones.
+        pub fn add_labels<S: Into<String>>(self, labels: Vec<S>) -> OutboundData<'a> {
+            self.extend("labels_add", labels)
+        }
+
+        /// Remove labels, keeping the others.
+        pub fn remove_labels<S: Into<String>>(self, labels: Vec<S>) -> OutboundData<'a> {
+            self.extend("labels_remove", labels)
+        }
+
+        /// Set priority by name.
+        pub fn priority<S: Into<String>>(mut self, priority: S) -> OutboundData<'a> {
+            self.inner.insert("priority", json!(priority.into()));
+            self
+        }
+
+        /// Set components by name, replacing the existing ones.
+        pub fn components<S: Into<String>>(mut self, components: Vec<S>) -> OutboundData<'a> {
+            let components = components
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<String>>();
+            self.inner.insert("components", json!(components));
+            self
+        }
+
+        /// Set fix versions by name, replacing the existing ones.
+        pub fn fix_versions<S: Into<String>>(mut self, versions: Vec<S>) -> OutboundData<'a> {
+            let versions = versions
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<String>>();
+            self.inner.insert("fix_versions", json!(versions));
+            self
+        }
+
+        fn extend<S: Into<String>>(mut self, key: &'a str, values: Vec<S>) -> OutboundData<'a> {
+            let entry = self.inner.entry(key).or_insert_with(|| json!([]));
+            if let Value::Array(a) = entry {
+                a.extend(values.into_iter().map(|v| json!(v.into())));
+            }
+            self
+        }
+
         /// Build outbound JSON data.
         pub fn build(self) -> Result<String, String> {
             if self.inner.is_empty() {
                 return Err("OutboundData build failed: empty field".to_string());
             }
 
+            if let Some(Value::Number(n)) = self.inner.get("time_spent_seconds") {
+                if n.as_u64() == Some(0) {
+                    return Err("OutboundData build failed: no time spent".to_string());
+                }
+            }
+
+            for key in ["labels_add", "labels_remove"] {
+                if let Some(Value::Array(labels)) = self.inner.get(key) {
+                    let invalid = labels
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .find(|l| l.is_empty() || l.contains(char::is_whitespace));
+                    if let Some(label) = invalid {
+                        return Err(format!(
+                            "OutboundData build failed: invalid label {:?}",
+                            label
+                        ));
+                    }
+                }
+            }
+
             serde_json::to_string(&self.inner)
                 .map_err(|e| format!("OutboundData build failed: {}", e.to_string()))
         }
@@ -219,4 +1198,79 @@ pub mod outbound {
                 .collect::<HashMap<&str, Value>>(),
         }
     }
+
+    /// Log work on an issue, use [`WorkingTime::parse_duration`](super::WorkingTime::parse_duration)
+    /// to get the seconds from a Jira duration.
+    pub fn log_work<'a, S: Into<String>>(
+        issue_key: S,
+        time_spent_seconds: u64,
+    ) -> OutboundData<'a> {
+        OutboundData {
+            inner: [
+                ("issue_key", json!(issue_key.into())),
+                ("time_spent_seconds", json!(time_spent_seconds)),
+            ]
+            .into_iter()
+            .collect::<HashMap<&str, Value>>(),
+        }
+    }
+
+    /// Search issues with the JQL query.
+    pub fn search<'a>(query: &Query) -> OutboundData<'a> {
+        OutboundData {
+            inner: [("jql", json!(query))]
+                .into_iter()
+                .collect::<HashMap<&str, Value>>(),
+        }
+    }
+
+    /// Link a web page like a GitHub pull request to an issue.
+    pub fn create_remote_link<'a, K: Into<String>, U: Into<String>, T: Into<String>>(
+        issue_key: K,
+        url: U,
+        title: T,
+    ) -> OutboundData<'a> {
+        OutboundData {
+            inner: [
+                ("issue_key", json!(issue_key.into())),
+                ("url", json!(url.into())),
+                ("title", json!(title.into())),
+            ]
+            .into_iter()
+            .collect::<HashMap<&str, Value>>(),
+        }
+    }
+
+    /// Create a subtask with the summary under the parent issue.
+    pub fn create_subtask<'a, P: Into<String>, S: Into<String>>(
+        parent_key: P,
+        summary: S,
+    ) -> OutboundData<'a> {
+        OutboundData {
+            inner: [
+                ("parent_key", json!(parent_key.into())),
+                ("summary", json!(summary.into())),
+            ]
+            .into_iter()
+            .collect::<HashMap<&str, Value>>(),
+        }
+    }
+
+    /// Link two issues with the link type name such as `Blocks`,
+    /// the keys map to `inwardIssue` and `outwardIssue` of the Jira issue link.
+    pub fn link_issues<'a, T: Into<String>, I: Into<String>, O: Into<String>>(
+        link_type: T,
+        inward_key: I,
+        outward_key: O,
+    ) -> OutboundData<'a> {
+        OutboundData {
+            inner: [
+                ("link_type", json!(link_type.into())),
+                ("inward_issue_key", json!(inward_key.into())),
+                ("outward_issue_key", json!(outward_key.into())),
+            ]
+            .into_iter()
+            .collect::<HashMap<&str, Value>>(),
+        }
+    }
 }
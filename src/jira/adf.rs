@@ -0,0 +1,967 @@
+//! [Atlassian Document Format](https://developer.atlassian.com/cloud/jira/platform/apis/document/structure/),
+//! used by the Jira Cloud REST API v3 for rich text like descriptions and comment bodies.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// The top-level `doc` node of an ADF document.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename = "doc")]
+pub struct Document {
+    pub version: u32,
+    #[serde(default = "Vec::new")]
+    pub content: Vec<Node>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct HeadingAttrs {
+    pub level: u8,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct OrderedListAttrs {
+    #[serde(default = "default_order")]
+    pub order: u32,
+}
+
+fn default_order() -> u32 {
+    1
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CodeBlockAttrs {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PanelAttrs {
+    /// One of `info`, `note`, `warning`, `success` and `error`.
+    #[serde(rename = "panelType")]
+    pub panel_type: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct MentionAttrs {
+    /// The account id of the mentioned user.
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct EmojiAttrs {
+    #[serde(rename = "shortName")]
+    pub short_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CardAttrs {
+    /// Missing when the card embeds its JSON-LD `data` instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct LinkAttrs {
+    pub href: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// A text formatting of a `text` node.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(remote = "Self", tag = "type", rename_all = "camelCase")]
+pub enum Mark {
+    Strong,
+    Em,
+    Code,
+    Strike,
+    Underline,
+    Link {
+        attrs: LinkAttrs,
+    },
+    /// Any other mark like `textColor` or `subsup`, or a malformed one,
+    /// dropped on rendering.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A block or inline node of an ADF document.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(remote = "Self", tag = "type", rename_all = "camelCase")]
+pub enum Node {
+    Paragraph {
+        #[serde(default = "Vec::new")]
+        content: Vec<Node>,
+    },
+    Heading {
+        attrs: HeadingAttrs,
+        #[serde(default = "Vec::new")]
+        content: Vec<Node>,
+    },
+    BulletList {
+        content: Vec<Node>,
+    },
+    OrderedList {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        attrs: Option<OrderedListAttrs>,
+        content: Vec<Node>,
+    },
+    ListItem {
+        content: Vec<Node>,
+    },
+    CodeBlock {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        attrs: Option<CodeBlockAttrs>,
+        #[serde(default = "Vec::new")]
+        content: Vec<Node>,
+    },
+    Blockquote {
+        content: Vec<Node>,
+    },
+    Panel {
+        attrs: PanelAttrs,
+        content: Vec<Node>,
+    },
+    Rule,
+    Table {
+        content: Vec<Node>,
+    },
+    TableRow {
+        content: Vec<Node>,
+    },
+    TableHeader {
+        content: Vec<Node>,
+    },
+    TableCell {
+        content: Vec<Node>,
+    },
+    Text {
+        text: String,
+        #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+        marks: Vec<Mark>,
+    },
+    HardBreak,
+    Mention {
+        attrs: MentionAttrs,
+    },
+    Emoji {
+        attrs: EmojiAttrs,
+    },
+    InlineCard {
+        attrs: CardAttrs,
+    },
+    /// Any other node like media, or a malformed one, dropped on rendering.
+    #[serde(other)]
+    Unknown,
+}
+
+// Nodes and marks which fail to deserialize become `Unknown`
+// instead of failing the whole document.
+impl<'de> Deserialize<'de> for Mark {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Mark, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        Ok(Mark::deserialize(value).unwrap_or(Mark::Unknown))
+    }
+}
+
+impl Serialize for Mark {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Mark::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Node, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        Ok(Node::deserialize(value).unwrap_or(Node::Unknown))
+    }
+}
+
+impl Serialize for Node {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Node::serialize(self, serializer)
+    }
+}
+
+/// A rich text field which is a plain string in the REST API v2
+/// and an ADF document in the REST API v3.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Body {
+    Text(String),
+    Adf(Document),
+}
+
+impl Body {
+    /// Renders the body into plain text.
+    pub fn to_plain_text(&self) -> String {
+        match self {
+            Body::Text(s) => s.clone(),
+            Body::Adf(d) => d.to_plain_text(),
+        }
+    }
+
+    /// Renders the body into Markdown, plain strings are returned as is.
+    pub fn to_markdown(&self) -> String {
+        match self {
+            Body::Text(s) => s.clone(),
+            Body::Adf(d) => d.to_markdown(),
+        }
+    }
+}
+
+impl Document {
+    /// Creates an ADF document with the nodes.
+    pub fn new(content: Vec<Node>) -> Document {
+        Document {
+            version: 1,
+            content,
+        }
+    }
+
+    /// Renders the document into plain text,
+    /// blocks are separated by blank lines.
+    pub fn to_plain_text(&self) -> String {
+        let mut out = String::new();
+        plain_blocks(&self.content, &mut out);
+        out.trim_end().to_string()
+    }
+
+    /// Renders the document into CommonMark with GFM strikethrough and tables.
+    ///
+    /// Text is escaped so that [`Document::from_markdown`] parses it back into
+    /// the same text, but the Markdown may differ from the one the document was
+    /// built from, like `1. ` becoming `1.`. Underlines, unknown marks and nodes,
+    /// and leading whitespace of text are dropped.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        markdown_blocks(&self.content, "", &mut out);
+        out.trim_end().to_string()
+    }
+
+    /// Builds an ADF document from Markdown.
+    ///
+    /// Supports ATX headings, paragraphs, bullet and ordered lists, fenced code blocks,
+    /// blockquotes, thematic breaks, `**strong**`, `*em*`, `` `code` ``, `~~strike~~`
+    /// and `[links](url)`. Line breaks inside paragraphs are kept as hard breaks.
+    ///
+    /// eg.
+    /// ```rust
+    /// use flows_connector_dsi::jira::adf::Document;
+    ///
+    /// let doc = Document::from_markdown("# Title\n\nSome **bold** text.");
+    /// assert_eq!(doc.to_markdown(), "# Title\n\nSome **bold** text.");
+    /// ```
+    pub fn from_markdown(markdown: &str) -> Document {
+        let lines = markdown.lines().collect::<Vec<_>>();
+        Document::new(parse_blocks(&lines))
+    }
+}
+
+fn plain_inline(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text { text, .. } => out.push_str(text),
+            Node::HardBreak => out.push('\n'),
+            Node::Mention { attrs } => match &attrs.text {
+                Some(text) => out.push_str(text),
+                None => {
+                    out.push('@');
+                    out.push_str(&attrs.id);
+                }
+            },
+            Node::Emoji { attrs } => {
+                out.push_str(attrs.text.as_deref().unwrap_or(&attrs.short_name))
+            }
+            Node::InlineCard { attrs } => out.push_str(attrs.url.as_deref().unwrap_or_default()),
+            _ => plain_blocks(std::slice::from_ref(node), out),
+        }
+    }
+}
+
+fn plain_blocks(nodes: &[Node], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Paragraph { content }
+            | Node::Heading { content, .. }
+            | Node::CodeBlock { content, .. } => {
+                plain_inline(content, out);
+                out.push_str("\n\n");
+            }
+            Node::ListItem { content }
+            | Node::TableRow { content }
+            | Node::TableHeader { content }
+            | Node::TableCell { content } => plain_blocks(content, out),
+            Node::BulletList { content } | Node::OrderedList { content, .. } => {
+                let start = match node {
+                    Node::OrderedList { attrs, .. } => Some(attrs.as_ref().map_or(1, |a| a.order)),
+                    _ => None,
+                };
+                for (i, item) in content.iter().enumerate() {
+                    match start {
+                        Some(start) => out.push_str(&format!("{}. ", start as usize + i)),
+                        None => out.push_str("- "),
+                    }
+                    let mut text = String::new();
+                    if let Node::ListItem { content } = item {
+                        plain_blocks(content, &mut text);
+                    }
+                    let lines = text.lines().filter(|l| !l.trim().is_empty());
+                    out.push_str(&lines.collect::<Vec<_>>().join("\n  "));
+                    out.push('\n');
+                }
+                out.push('\n');
+            }
+            Node::Blockquote { content } | Node::Panel { content, .. } => {
+                plain_blocks(content, out)
+            }
+            Node::Table { content } => {
+                for row in content {
+                    if let Node::TableRow { content } = row {
+                        let cells = content
+                            .iter()
+                            .map(|cell| {
+                                let mut text = String::new();
+                                if let Node::TableHeader { content } | Node::TableCell { content } =
+                                    cell
+                                {
+                                    plain_blocks(content, &mut text);
+                                }
+                                text.trim().replace('\n', " ")
+                            })
+                            .collect::<Vec<_>>();
+                        out.push_str(&cells.join("\t"));
+                        out.push('\n');
+                    }
+                }
+                out.push('\n');
+            }
+            Node::Rule | Node::Unknown => {}
+            _ => plain_inline(std::slice::from_ref(node), out),
+        }
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut prev = None;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        // Underscores inside words like `snake_case` are never emphasis.
+        let intraword = c == '_'
+            && prev.is_some_and(char::is_alphanumeric)
+            && chars.peek().is_some_and(|n| n.is_alphanumeric());
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '~') && !intraword {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        prev = Some(c);
+    }
+    escaped
+}
+
+/// Escapes the markers of `text` starting a line which would start a block,
+/// like `# `, `> `, `- ` or `1. `.
+fn escape_line_start(text: &str) -> String {
+    let body = text.trim_start();
+    let (indent, body) = text.split_at(text.len() - body.len());
+    let mut chars = body.chars();
+    let first = chars.next();
+    let second = chars.next();
+    let ends_marker = |c: Option<char>| match c {
+        Some(c) => c.is_whitespace(),
+        None => true,
+    };
+
+    let marker = match first {
+        Some('>') => Some(0),
+        Some('#') if ends_marker(body.chars().find(|&c| c != '#')) => Some(0),
+        Some('-') if ends_marker(second) || second == Some('-') => Some(0),
+        Some('+') if ends_marker(second) => Some(0),
+        Some(c) if c.is_ascii_digit() => {
+            let digits = body.chars().take_while(char::is_ascii_digit).count();
+            let mut rest = body[digits..].chars();
+            match rest.next() {
+                Some('.' | ')') if ends_marker(rest.next()) => Some(digits),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+
+    match marker {
+        Some(at) => format!("{}{}\\{}", indent, &body[..at], &body[at..]),
+        None => text.to_string(),
+    }
+}
+
+/// Wraps the link destination in `<...>` if it would end the link early.
+fn escape_href(href: &str) -> String {
+    if href.is_empty() || href.contains(|c: char| c.is_whitespace() || "()<>\\".contains(c)) {
+        let mut escaped = String::from("<");
+        for c in href.chars() {
+            if matches!(c, '<' | '>' | '\\') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped.push('>');
+        escaped
+    } else {
+        href.to_string()
+    }
+}
+
+fn open_mark(mark: &Mark) -> &'static str {
+    match mark {
+        Mark::Strong => "**",
+        Mark::Em => "*",
+        Mark::Strike => "~~",
+        Mark::Link { .. } => "[",
+        Mark::Code | Mark::Underline | Mark::Unknown => "",
+    }
+}
+
+fn close_mark(mark: &Mark, out: &mut String) {
+    match mark {
+        Mark::Link { attrs } => out.push_str(&format!("]({})", escape_href(&attrs.href))),
+        _ => out.push_str(open_mark(mark)),
+    }
+}
+
+/// Renders inline nodes, keeping the marks shared by adjacent text nodes open
+/// so that `**a *b***` is not rendered as `**a *****b***`.
+fn markdown_inline(nodes: &[Node], indent: &str, out: &mut String) {
+    let mut open: Vec<&Mark> = Vec::new();
+    let mut line_start = out.len();
+
+    for node in nodes {
+        let marks = match node {
+            Node::Text { marks, .. } => marks
+                .iter()
+                .filter(|m| !open_mark(m).is_empty())
+                .collect::<Vec<_>>(),
+            _ => Vec::new(),
+        };
+
+        let keep = open.iter().take_while(|m| marks.contains(m)).count();
+        while open.len() > keep {
+            close_mark(open.pop().unwrap(), out);
+        }
+        for mark in marks {
+            if !open.contains(&mark) {
+                out.push_str(open_mark(mark));
+                open.push(mark);
+            }
+        }
+
+        match node {
+            Node::Text { text, marks } if marks.contains(&Mark::Code) => {
+                if text.contains('`') {
+                    out.push_str(&format!("`` {} ``", text));
+                } else {
+                    out.push_str(&format!("`{}`", text));
+                }
+            }
+            Node::Text { text, .. } if out.len() == line_start => {
+                out.push_str(&escape_line_start(&escape_markdown(text)))
+            }
+            Node::Text { text, .. } => out.push_str(&escape_markdown(text)),
+            Node::HardBreak => {
+                out.push_str("\\\n");
+                out.push_str(indent);
+                line_start = out.len();
+            }
+            Node::InlineCard { attrs } => {
+                if let Some(url) = &attrs.url {
+                    out.push_str(&format!("<{}>", url));
+                }
+            }
+            _ => plain_inline(std::slice::from_ref(node), out),
+        }
+    }
+
+    while let Some(mark) = open.pop() {
+        close_mark(mark, out);
+    }
+}
+
+fn markdown_blocks(nodes: &[Node], indent: &str, out: &mut String) {
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+            out.push_str(indent.trim_end());
+            out.push('\n');
+            out.push_str(indent);
+        }
+
+        match node {
+            Node::Paragraph { content } => markdown_inline(content, indent, out),
+            Node::Heading { attrs, content } => {
+                out.push_str(&"#".repeat(attrs.level.clamp(1, 6) as usize));
+                out.push(' ');
+                markdown_inline(content, indent, out);
+            }
+            Node::CodeBlock { attrs, content } => {
+                let mut code = String::new();
+                plain_inline(content, &mut code);
+                out.push_str("```");
+                if let Some(language) = attrs.as_ref().and_then(|a| a.language.as_ref()) {
+                    out.push_str(language);
+                }
+                for line in code.lines() {
+                    out.push('\n');
+                    out.push_str(indent);
+                    out.push_str(line);
+                }
+                out.push('\n');
+                out.push_str(indent);
+                out.push_str("```");
+            }
+            Node::BulletList { content } | Node::OrderedList { content, .. } => {
+                let start = match node {
+                    Node::OrderedList { attrs, .. } => Some(attrs.as_ref().map_or(1, |a| a.order)),
+                    _ => None,
+                };
+                for (i, item) in content.iter().enumerate() {
+                    if i > 0 {
+                        out.push('\n');
+                        out.push_str(indent);
+                    }
+                    let marker = match start {
+                        Some(start) => format!("{}. ", start as usize + i),
+                        None => "- ".to_string(),
+                    };
+                    out.push_str(&marker);
+                    if let Node::ListItem { content } = item {
+                        let nested = format!("{}{}", indent, " ".repeat(marker.len()));
+                        markdown_list_item(content, &nested, out);
+                    }
+                }
+            }
+            Node::Blockquote { content } | Node::Panel { content, .. } => {
+                let nested = format!("{}> ", indent);
+                out.push_str("> ");
+                markdown_blocks(content, &nested, out);
+            }
+            Node::Rule => out.push_str("---"),
+            Node::Table { content } => markdown_table(content, indent, out),
+            Node::Unknown => {}
+            _ => markdown_inline(std::slice::from_ref(node), indent, out),
+        }
+    }
+}
+
+/// Renders list item blocks without blank lines between them, keeping the list tight.
+fn markdown_list_item(nodes: &[Node], indent: &str, out: &mut String) {
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+            out.push_str(indent);
+        }
+        markdown_blocks(std::slice::from_ref(node), indent, out);
+    }
+}
+
+fn markdown_table(rows: &[Node], indent: &str, out: &mut String) {
+    for (i, row) in rows.iter().enumerate() {
+        let Node::TableRow { content } = row else {
+            continue;
+        };
+
+        if i > 0 {
+            out.push('\n');
+            out.push_str(indent);
+        }
+        let cells = content
+            .iter()
+            .map(|cell| {
+                let mut text = String::new();
+                if let Node::TableHeader { content } | Node::TableCell { content } = cell {
+                    for block in content {
+                        match block {
+                            Node::Paragraph { content } => markdown_inline(content, "", &mut text),
+                            _ => plain_blocks(std::slice::from_ref(block), &mut text),
+                        }
+                    }
+                }
+                text.trim().replace('\n', " ").replace('|', "\\|")
+            })
+            .collect::<Vec<_>>();
+        out.push_str(&format!("| {} |", cells.join(" | ")));
+
+        // GFM tables always have a header row.
+        if i == 0 {
+            out.push('\n');
+            out.push_str(indent);
+            out.push_str(&format!("|{}", " --- |".repeat(cells.len())));
+        }
+    }
+}
+
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let rest = &line[level..];
+    if (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')) {
+        Some((level as u8, rest.trim()))
+    } else {
+        None
+    }
+}
+
+fn is_rule(line: &str) -> bool {
+    let chars = line
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<Vec<_>>();
+    chars.len() >= 3 && ['-', '*', '_'].iter().any(|m| chars.iter().all(|c| c == m))
+}
+
+/// The text after a list marker followed by a space or the end of the line.
+fn after_marker<'l>(line: &'l str, marker: &str) -> Option<&'l str> {
+    let rest = line.strip_prefix(marker)?;
+    if rest.is_empty() {
+        Some(rest)
+    } else {
+        rest.strip_prefix(' ')
+    }
+}
+
+fn bullet_item(line: &str) -> Option<&str> {
+    ["-", "*", "+"]
+        .iter()
+        .find_map(|marker| after_marker(line, marker))
+}
+
+fn ordered_item(line: &str) -> Option<(u32, &str)> {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let rest = after_marker(&line[digits..], ".").or_else(|| after_marker(&line[digits..], ")"))?;
+    Some((line[..digits].parse().ok()?, rest))
+}
+
+fn is_block_start(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    heading(trimmed).is_some()
+        || trimmed.starts_with("```")
+        || trimmed.starts_with('>')
+        || is_rule(trimmed)
+        // Empty list items don't interrupt a paragraph.
+        || bullet_item(trimmed).is_some_and(|rest| !rest.is_empty())
+        || ordered_item(trimmed).is_some_and(|(_, rest)| !rest.is_empty())
+}
+
+fn parse_blocks(lines: &[&str]) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim_start();
+
+        if line.is_empty() {
+            i += 1;
+        } else if let Some(fence) = line.strip_prefix("```") {
+            let language = Some(fence.trim().to_string()).filter(|l| !l.is_empty());
+            let mut code = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].trim_start().starts_with("```") {
+                code.push(lines[i]);
+                i += 1;
+            }
+            i += 1;
+            nodes.push(Node::CodeBlock {
+                attrs: language.map(|language| CodeBlockAttrs {
+                    language: Some(language),
+                }),
+                content: if code.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![Node::Text {
+                        text: code.join("\n"),
+                        marks: Vec::new(),
+                    }]
+                },
+            });
+        } else if let Some((level, text)) = heading(line) {
+            nodes.push(Node::Heading {
+                attrs: HeadingAttrs { level },
+                content: parse_inline(text),
+            });
+            i += 1;
+        } else if is_rule(line) {
+            nodes.push(Node::Rule);
+            i += 1;
+        } else if line.starts_with('>') {
+            let mut quoted = Vec::new();
+            while i < lines.len() {
+                match lines[i].trim_start().strip_prefix('>') {
+                    Some(rest) => quoted.push(rest.strip_prefix(' ').unwrap_or(rest)),
+                    None => break,
+                }
+                i += 1;
+            }
+            nodes.push(Node::Blockquote {
+                content: parse_blocks(&quoted),
+            });
+        } else if bullet_item(line).is_some() || ordered_item(line).is_some() {
+            let ordered = ordered_item(line).map(|(order, _)| order);
+            let indent = lines[i].len() - line.len();
+            let mut items: Vec<Vec<&str>> = Vec::new();
+            let mut offset = 0;
+
+            while i < lines.len() {
+                let raw = lines[i];
+                let trimmed = raw.trim_start();
+                let item_indent = raw.len() - trimmed.len();
+                let item = match ordered {
+                    Some(_) => ordered_item(trimmed).map(|(_, rest)| rest),
+                    None => bullet_item(trimmed),
+                };
+
+                match item {
+                    Some(rest) if item_indent <= indent => {
+                        offset = raw.len() - rest.len();
+                        items.push(vec![rest]);
+                    }
+                    // Continuation lines and nested lists are indented deeper than the marker.
+                    _ if !trimmed.is_empty() && item_indent > indent => {
+                        let continuation = raw.get(item_indent.min(offset)..).unwrap_or(trimmed);
+                        items.last_mut().unwrap().push(continuation);
+                    }
+                    _ => break,
+                }
+                i += 1;
+            }
+
+            let content = items
+                .iter()
+                .map(|item| Node::ListItem {
+                    content: parse_blocks(item),
+                })
+                .collect();
+            nodes.push(match ordered {
+                Some(order) => Node::OrderedList {
+                    attrs: Some(OrderedListAttrs { order }),
+                    content,
+                },
+                None => Node::BulletList { content },
+            });
+        } else {
+            let mut paragraph = vec![line];
+            i += 1;
+            while i < lines.len() && !lines[i].trim().is_empty() && !is_block_start(lines[i]) {
+                paragraph.push(lines[i].trim_start());
+                i += 1;
+            }
+
+            let mut content = Vec::new();
+            for (n, line) in paragraph.iter().enumerate() {
+                if n > 0 {
+                    content.push(Node::HardBreak);
+                }
+                // Every line break is a hard break, so drop the explicit hard break markers,
+                // an odd number of trailing backslashes ends with an unescaped one.
+                let line = line.trim_end();
+                let backslashes = line.chars().rev().take_while(|&c| c == '\\').count();
+                let line = if n + 1 < paragraph.len() && backslashes % 2 == 1 {
+                    &line[..line.len() - 1]
+                } else {
+                    line
+                };
+                content.extend(parse_inline(line));
+            }
+            nodes.push(Node::Paragraph { content });
+        }
+    }
+
+    nodes
+}
+
+fn push_text(nodes: &mut Vec<Node>, text: &str, marks: &[Mark]) {
+    if text.is_empty() {
+        return;
+    }
+
+    if let Some(Node::Text {
+        text: last,
+        marks: last_marks,
+    }) = nodes.last_mut()
+    {
+        if last_marks == marks {
+            last.push_str(text);
+            return;
+        }
+    }
+
+    nodes.push(Node::Text {
+        text: text.to_string(),
+        marks: marks.to_vec(),
+    });
+}
+
+fn parse_inline(text: &str) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    parse_inline_marked(text, &[], &mut nodes);
+    nodes
+}
+
+fn parse_inline_marked(text: &str, marks: &[Mark], nodes: &mut Vec<Node>) {
+    let mut rest = text;
+    let mut prev = None;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '\\' {
+            match rest[1..].chars().next() {
+                Some(escaped) if escaped.is_ascii_punctuation() => {
+                    push_text(nodes, &rest[1..1 + escaped.len_utf8()], marks);
+                    rest = &rest[1 + escaped.len_utf8()..];
+                }
+                _ => {
+                    push_text(nodes, "\\", marks);
+                    rest = &rest[1..];
+                }
+            }
+            continue;
+        }
+
+        if c == '`' {
+            let fence_len = rest.chars().take_while(|&c| c == '`').count();
+            let fence = &rest[..fence_len];
+            if let Some(end) = rest[fence_len..].find(fence) {
+                let code = &rest[fence_len..fence_len + end];
+                let code = code
+                    .strip_prefix(' ')
+                    .and_then(|c| c.strip_suffix(' '))
+                    .filter(|c| !c.is_empty())
+                    .unwrap_or(code);
+                let mut code_marks = marks.to_vec();
+                code_marks.push(Mark::Code);
+                push_text(nodes, code, &code_marks);
+                rest = &rest[fence_len + end + fence_len..];
+                continue;
+            }
+        }
+
+        if c == '[' {
+            if let Some((label, href, len)) = link(rest) {
+                let mut link_marks = marks.to_vec();
+                link_marks.push(Mark::Link {
+                    attrs: LinkAttrs { href, title: None },
+                });
+                parse_inline_marked(label, &link_marks, nodes);
+                rest = &rest[len..];
+                continue;
+            }
+        }
+
+        let delimited = [
+            ("**", Mark::Strong),
+            ("__", Mark::Strong),
+            ("~~", Mark::Strike),
+            ("*", Mark::Em),
+            ("_", Mark::Em),
+        ]
+        .into_iter()
+        .find_map(|(delimiter, mark)| {
+            let inner = rest.strip_prefix(delimiter)?;
+            // Underscores inside words like `snake_case` are not emphasis.
+            let intraword = delimiter.starts_with('_') && prev.is_some_and(char::is_alphanumeric);
+            if intraword || inner.starts_with(char::is_whitespace) {
+                return None;
+            }
+            let end = find_closing(inner, delimiter)?;
+            Some((mark, &inner[..end], delimiter.len() * 2 + end))
+        });
+
+        if let Some((mark, inner, len)) = delimited {
+            let mut inner_marks = marks.to_vec();
+            inner_marks.push(mark);
+            parse_inline_marked(inner, &inner_marks, nodes);
+            prev = rest[..len].chars().last();
+            rest = &rest[len..];
+            continue;
+        }
+
+        push_text(nodes, &rest[..c.len_utf8()], marks);
+        prev = Some(c);
+        rest = &rest[c.len_utf8()..];
+    }
+}
+
+/// Finds the closing `delimiter` which is not preceded by whitespace.
+///
+/// In a longer run of the delimiter character like `***`, the closing delimiter
+/// is the end of the run, so that `**a *b***` closes the inner emphasis first.
+/// A backslash before the run only escapes its first character, so `a\~~~`
+/// still closes a strikethrough.
+fn find_closing(text: &str, delimiter: &str) -> Option<usize> {
+    let marker = delimiter.chars().next()?;
+    let mut from = 0;
+    while let Some(pos) = text[from..].find(delimiter) {
+        let run_start = from + pos;
+        let run_len = text[run_start..]
+            .chars()
+            .take_while(|&c| c == marker)
+            .count();
+        from = run_start + run_len;
+
+        let backslashes = text[..run_start]
+            .chars()
+            .rev()
+            .take_while(|&c| c == '\\')
+            .count();
+        let (start, run) = match backslashes % 2 {
+            1 => (run_start + 1, run_len - 1),
+            _ => (run_start, run_len),
+        };
+        if run < delimiter.len() {
+            continue;
+        }
+
+        let end = start + run - delimiter.len();
+        let before = text[..start].chars().last();
+        let after = text[end + delimiter.len()..].chars().next();
+        // A single delimiter skips a double one, which belongs to a nested strong emphasis.
+        let nested = delimiter.len() == 1 && run == 2;
+        let intraword = marker == '_' && after.is_some_and(char::is_alphanumeric);
+        if start > 0 && !before.is_some_and(char::is_whitespace) && !nested && !intraword {
+            return Some(end);
+        }
+    }
+    None
+}
+
+/// Parses `[label](href)` or `[label](<href>)` at the start of `text`,
+/// returning the label, unescaped href and total length.
+fn link(text: &str) -> Option<(&str, String, usize)> {
+    let label_end = text.find("](")?;
+    let label = &text[1..label_end];
+    if label.contains('[') {
+        return None;
+    }
+
+    let href_start = label_end + 2;
+    if text[href_start..].starts_with('<') {
+        let mut href = String::new();
+        let mut chars = text[href_start + 1..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => href.extend(chars.next().map(|(_, c)| c)),
+                '>' => {
+                    let end = href_start + 1 + i + 1;
+                    return text[end..].starts_with(')').then(|| (label, href, end + 1));
+                }
+                '\n' | '<' => return None,
+                _ => href.push(c),
+            }
+        }
+        return None;
+    }
+
+    let href_end = href_start + text[href_start..].find(')')?;
+    let href = text[href_start..href_end].trim();
+    Some((label, href.to_string(), href_end + 1))
+}
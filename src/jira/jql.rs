@@ -0,0 +1,519 @@
+//! A typed builder for [JQL](https://support.atlassian.com/jira-software-cloud/docs/use-advanced-search-with-jira-query-language-jql/)
+//! queries, taking care of quoting field names and values.
+//!
+//! eg.
+//! ```rust
+//! use flows_connector_dsi::jira::jql::{current_user, field, Order, Query};
+//!
+//! let query = Query::new()
+//!     .filter(field("project").eq("HT"))
+//!     .filter(field("assignee").eq(current_user()))
+//!     .filter(field("status").not_in(vec!["Done", "Won't Do"]))
+//!     .order_by("priority", Order::Desc);
+//!
+//! assert_eq!(
+//!     query.to_string(),
+//!     r#"project = "HT" AND assignee = currentUser() AND status NOT IN ("Done", "Won't Do") ORDER BY priority DESC"#
+//! );
+//! ```
+
+use std::{fmt, ops};
+
+use serde::{Serialize, Serializer};
+
+/// Quotes a field name or value, escaping quotes and backslashes.
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Field names like `status`, `fixVersion` and `cf[10020]` are left bare,
+/// the others like `Story Points` are quoted.
+fn field_name(name: &str) -> String {
+    let custom_id = name
+        .strip_prefix("cf[")
+        .and_then(|s| s.strip_suffix(']'))
+        .is_some_and(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()));
+    let plain = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+
+    if custom_id || plain {
+        name.to_string()
+    } else {
+        quote(name)
+    }
+}
+
+/// The right hand side of a clause.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    /// A quoted string, including dates like `2022-10-31` and
+    /// relative dates like `-1w`.
+    Text(String),
+    Number(i64),
+    /// A function call like `currentUser()`, the arguments are quoted.
+    Function {
+        name: String,
+        args: Vec<String>,
+    },
+    /// The `EMPTY` keyword.
+    Empty,
+    /// A list like `("Done", "Closed")`, used by `WAS IN` and `WAS NOT IN`.
+    /// An empty list renders `()`, which Jira rejects as invalid JQL.
+    List(Vec<Operand>),
+}
+
+impl From<&str> for Operand {
+    fn from(s: &str) -> Self {
+        Operand::Text(s.to_string())
+    }
+}
+
+impl From<String> for Operand {
+    fn from(s: String) -> Self {
+        Operand::Text(s)
+    }
+}
+
+impl From<i64> for Operand {
+    fn from(n: i64) -> Self {
+        Operand::Number(n)
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Text(s) => f.write_str(&quote(s)),
+            Operand::Number(n) => write!(f, "{}", n),
+            Operand::Function { name, args } => {
+                let args = args.iter().map(|a| quote(a)).collect::<Vec<_>>();
+                write!(f, "{}({})", name, args.join(", "))
+            }
+            Operand::Empty => f.write_str("EMPTY"),
+            Operand::List(values) => f.write_str(&list(values)),
+        }
+    }
+}
+
+/// Call any JQL function by name.
+pub fn function<N: Into<String>, A: Into<String>>(name: N, args: Vec<A>) -> Operand {
+    Operand::Function {
+        name: name.into(),
+        args: args.into_iter().map(Into::into).collect(),
+    }
+}
+
+/// `currentUser()`
+pub fn current_user() -> Operand {
+    function("currentUser", Vec::<String>::new())
+}
+
+/// `now()`
+pub fn now() -> Operand {
+    function("now", Vec::<String>::new())
+}
+
+/// `openSprints()`
+pub fn open_sprints() -> Operand {
+    function("openSprints", Vec::<String>::new())
+}
+
+/// `closedSprints()`
+pub fn closed_sprints() -> Operand {
+    function("closedSprints", Vec::<String>::new())
+}
+
+/// `releasedVersions("project")`
+pub fn released_versions<S: Into<String>>(project: S) -> Operand {
+    function("releasedVersions", vec![project])
+}
+
+/// `unreleasedVersions("project")`
+pub fn unreleased_versions<S: Into<String>>(project: S) -> Operand {
+    function("unreleasedVersions", vec![project])
+}
+
+fn list(values: &[Operand]) -> String {
+    let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    format!("({})", values.join(", "))
+}
+
+/// A search clause, combine them with [`Clause::and`], [`Clause::or`] and `!`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Clause {
+    /// `field op value`, like `status = "Done"` or `summary ~ "login"`.
+    Compare {
+        field: String,
+        op: &'static str,
+        value: Operand,
+    },
+    /// `field IN (values)` or `field NOT IN (values)`,
+    /// Jira rejects an empty `values` rendered as `IN ()`.
+    In {
+        field: String,
+        not: bool,
+        values: Vec<Operand>,
+    },
+    /// `field WAS value`, `field CHANGED` and their predicates.
+    History(History),
+    And(Vec<Clause>),
+    Or(Vec<Clause>),
+    Not(Box<Clause>),
+}
+
+impl Clause {
+    /// Both clauses must match.
+    pub fn and<C: Into<Clause>>(self, other: C) -> Clause {
+        match (self, other.into()) {
+            (Clause::And(mut a), Clause::And(b)) => {
+                a.extend(b);
+                Clause::And(a)
+            }
+            (Clause::And(mut a), b) => {
+                a.push(b);
+                Clause::And(a)
+            }
+            (a, b) => Clause::And(vec![a, b]),
+        }
+    }
+
+    /// Either clause must match.
+    pub fn or<C: Into<Clause>>(self, other: C) -> Clause {
+        match (self, other.into()) {
+            (Clause::Or(mut a), Clause::Or(b)) => {
+                a.extend(b);
+                Clause::Or(a)
+            }
+            (Clause::Or(mut a), b) => {
+                a.push(b);
+                Clause::Or(a)
+            }
+            (a, b) => Clause::Or(vec![a, b]),
+        }
+    }
+
+    fn is_compound(&self) -> bool {
+        matches!(self, Clause::And(_) | Clause::Or(_))
+    }
+}
+
+impl ops::Not for Clause {
+    type Output = Clause;
+
+    /// The clause must not match.
+    fn not(self) -> Clause {
+        Clause::Not(Box::new(self))
+    }
+}
+
+impl fmt::Display for Clause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Clause::Compare { field, op, value } => {
+                write!(f, "{} {} {}", field_name(field), op, value)
+            }
+            Clause::In { field, not, values } => write!(
+                f,
+                "{} {}IN {}",
+                field_name(field),
+                if *not { "NOT " } else { "" },
+                list(values)
+            ),
+            Clause::History(h) => write!(f, "{}", h),
+            Clause::And(clauses) => {
+                let clauses = clauses
+                    .iter()
+                    .map(|c| match c {
+                        Clause::Or(_) => format!("({})", c),
+                        _ => c.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                f.write_str(&clauses.join(" AND "))
+            }
+            Clause::Or(clauses) => {
+                let clauses = clauses.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+                f.write_str(&clauses.join(" OR "))
+            }
+            Clause::Not(c) if c.is_compound() => write!(f, "NOT ({})", c),
+            Clause::Not(c) => write!(f, "NOT {}", c),
+        }
+    }
+}
+
+/// A `WAS` or `CHANGED` clause, narrowed down with predicates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct History {
+    field: String,
+    operator: String,
+    operand: Option<Operand>,
+    predicates: Vec<String>,
+}
+
+impl History {
+    fn predicate(mut self, keyword: &str, value: Operand) -> History {
+        self.predicates.push(format!("{} {}", keyword, value));
+        self
+    }
+
+    /// The value before the change, only used on `CHANGED`.
+    pub fn from<V: Into<Operand>>(self, value: V) -> History {
+        self.predicate("FROM", value.into())
+    }
+
+    /// The value after the change, only used on `CHANGED`.
+    pub fn to<V: Into<Operand>>(self, value: V) -> History {
+        self.predicate("TO", value.into())
+    }
+
+    /// The user who made the change.
+    pub fn by<V: Into<Operand>>(self, user: V) -> History {
+        self.predicate("BY", user.into())
+    }
+
+    pub fn after<V: Into<Operand>>(self, date: V) -> History {
+        self.predicate("AFTER", date.into())
+    }
+
+    pub fn before<V: Into<Operand>>(self, date: V) -> History {
+        self.predicate("BEFORE", date.into())
+    }
+
+    pub fn on<V: Into<Operand>>(self, date: V) -> History {
+        self.predicate("ON", date.into())
+    }
+
+    pub fn during<S: Into<Operand>, E: Into<Operand>>(mut self, start: S, end: E) -> History {
+        self.predicates
+            .push(format!("DURING ({}, {})", start.into(), end.into()));
+        self
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", field_name(&self.field), self.operator)?;
+        if let Some(operand) = &self.operand {
+            write!(f, " {}", operand)?;
+        }
+        for predicate in &self.predicates {
+            write!(f, " {}", predicate)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<History> for Clause {
+    fn from(h: History) -> Self {
+        Clause::History(h)
+    }
+}
+
+/// A field to build clauses on, names with spaces are quoted automatically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field(String);
+
+/// Start a clause on the field.
+pub fn field<S: Into<String>>(name: S) -> Field {
+    Field(name.into())
+}
+
+impl Field {
+    fn compare(self, op: &'static str, value: Operand) -> Clause {
+        Clause::Compare {
+            field: self.0,
+            op,
+            value,
+        }
+    }
+
+    fn history(self, operator: &str, operand: Option<Operand>) -> History {
+        History {
+            field: self.0,
+            operator: operator.to_string(),
+            operand,
+            predicates: vec![],
+        }
+    }
+
+    /// `=`
+    pub fn eq<V: Into<Operand>>(self, value: V) -> Clause {
+        self.compare("=", value.into())
+    }
+
+    /// `!=`
+    pub fn ne<V: Into<Operand>>(self, value: V) -> Clause {
+        self.compare("!=", value.into())
+    }
+
+    /// `>`
+    pub fn gt<V: Into<Operand>>(self, value: V) -> Clause {
+        self.compare(">", value.into())
+    }
+
+    /// `>=`
+    pub fn ge<V: Into<Operand>>(self, value: V) -> Clause {
+        self.compare(">=", value.into())
+    }
+
+    /// `<`
+    pub fn lt<V: Into<Operand>>(self, value: V) -> Clause {
+        self.compare("<", value.into())
+    }
+
+    /// `<=`
+    pub fn le<V: Into<Operand>>(self, value: V) -> Clause {
+        self.compare("<=", value.into())
+    }
+
+    /// `~`, the text search of fields like `summary`.
+    pub fn contains<V: Into<Operand>>(self, value: V) -> Clause {
+        self.compare("~", value.into())
+    }
+
+    /// `!~`
+    pub fn not_contains<V: Into<Operand>>(self, value: V) -> Clause {
+        self.compare("!~", value.into())
+    }
+
+    /// `IS EMPTY`
+    pub fn is_empty(self) -> Clause {
+        self.compare("IS", Operand::Empty)
+    }
+
+    /// `IS NOT EMPTY`
+    pub fn is_not_empty(self) -> Clause {
+        self.compare("IS NOT", Operand::Empty)
+    }
+
+    /// `IN (values)`, `values` must not be empty.
+    pub fn is_in<V: Into<Operand>>(self, values: Vec<V>) -> Clause {
+        Clause::In {
+            field: self.0,
+            not: false,
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// `NOT IN (values)`, `values` must not be empty.
+    pub fn not_in<V: Into<Operand>>(self, values: Vec<V>) -> Clause {
+        Clause::In {
+            field: self.0,
+            not: true,
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// `WAS value`
+    pub fn was<V: Into<Operand>>(self, value: V) -> History {
+        self.history("WAS", Some(value.into()))
+    }
+
+    /// `WAS NOT value`
+    pub fn was_not<V: Into<Operand>>(self, value: V) -> History {
+        self.history("WAS NOT", Some(value.into()))
+    }
+
+    /// `WAS IN (values)`, `values` must not be empty.
+    pub fn was_in<V: Into<Operand>>(self, values: Vec<V>) -> History {
+        let values = values.into_iter().map(Into::into).collect();
+        self.history("WAS IN", Some(Operand::List(values)))
+    }
+
+    /// `WAS NOT IN (values)`, `values` must not be empty.
+    pub fn was_not_in<V: Into<Operand>>(self, values: Vec<V>) -> History {
+        let values = values.into_iter().map(Into::into).collect();
+        self.history("WAS NOT IN", Some(Operand::List(values)))
+    }
+
+    /// `CHANGED`
+    pub fn changed(self) -> History {
+        self.history("CHANGED", None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+/// A JQL query, serialized as its string form.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Query {
+    clause: Option<Clause>,
+    order_by: Vec<(String, Order)>,
+}
+
+impl Query {
+    pub fn new() -> Query {
+        Query::default()
+    }
+
+    /// Add a clause, multiple clauses are combined with `AND`.
+    pub fn filter<C: Into<Clause>>(mut self, clause: C) -> Query {
+        self.clause = Some(match self.clause.take() {
+            Some(c) => c.and(clause),
+            None => clause.into(),
+        });
+        self
+    }
+
+    /// Add a sort key, earlier keys take precedence.
+    pub fn order_by<S: Into<String>>(mut self, field: S, order: Order) -> Query {
+        self.order_by.push((field.into(), order));
+        self
+    }
+}
+
+impl From<Clause> for Query {
+    fn from(clause: Clause) -> Self {
+        Query::new().filter(clause)
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(clause) = &self.clause {
+            write!(f, "{}", clause)?;
+        }
+        if !self.order_by.is_empty() {
+            if self.clause.is_some() {
+                f.write_str(" ")?;
+            }
+            let keys = self
+                .order_by
+                .iter()
+                .map(|(field, order)| {
+                    let order = match order {
+                        Order::Asc => "ASC",
+                        Order::Desc => "DESC",
+                    };
+                    format!("{} {}", field_name(field), order)
+                })
+                .collect::<Vec<_>>();
+            write!(f, "ORDER BY {}", keys.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for Query {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
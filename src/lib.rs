@@ -8,6 +8,7 @@
 /// [here](https://github.com/second-state/flow-functions).
 
 pub mod github;
+pub mod github_jira;
 pub mod gmail;
 pub mod monday;
 pub mod notion;
@@ -1,6 +1,6 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::Deref};
 
-use serde::Deserialize;
+use serde::{de, Deserialize, Deserializer};
 use serde_json::Value;
 
 #[derive(Deserialize, Debug)]
@@ -185,6 +185,26 @@ pub struct ChatJoinRequest {
     pub invite_link: Option<ChatInviteLink>,
 }
 
+#[derive(Deserialize, Debug)]
+pub struct CallbackQuery {
+    /// Unique identifier for this query.
+    pub id: String,
+    /// Sender.
+    pub from: User,
+    /// Message with the callback button that originated the query.
+    /// Note that message content and message date will not be available if the message is too old.
+    pub message: Option<Box<Message>>,
+    /// Identifier of the message sent via the bot in inline mode, that originated the query.
+    pub inline_message_id: Option<String>,
+    /// Global identifier, uniquely corresponding to the chat to which
+    /// the message with the callback button was sent.
+    pub chat_instance: String,
+    /// Data associated with the callback button.
+    pub data: Option<String>,
+    /// Short name of a Game to be returned, serves as the unique identifier for the game.
+    pub game_short_name: Option<String>,
+}
+
 #[derive(Deserialize, Debug)]
 pub enum InboundData {
     #[serde(rename = "message")]
@@ -201,15 +221,80 @@ pub enum InboundData {
     ChatMember(ChatMemberUpdated),
     #[serde(rename = "chat_join_request")]
     ChatJoinRequest(ChatJoinRequest),
+    #[serde(rename = "callback_query")]
+    CallbackQuery(CallbackQuery),
+    /// An update type not supported yet, keyed by its field name like `poll`.
+    /// The `key` is empty and the `value` is null when the update has no other field.
+    #[serde(skip_deserializing)]
+    Unknown { key: String, value: Value },
+}
+
+/// The field names of the supported update types.
+const UPDATE_KINDS: &[&str] = &[
+    "message",
+    "edited_message",
+    "channel_post",
+    "edited_channel_post",
+    "my_chat_member",
+    "chat_member",
+    "chat_join_request",
+    "callback_query",
+];
+
+/// An incoming update, derefs to its [`InboundData`].
+#[derive(Debug)]
+pub struct Update {
+    /// The update's unique identifier, use it to ignore repeated updates.
+    /// `None` when the payload doesn't carry it.
+    pub update_id: Option<i64>,
+    pub kind: InboundData,
+}
+
+impl<'de> Deserialize<'de> for Update {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut map = serde_json::Map::<String, Value>::deserialize(deserializer)?;
+        let update_id = match map.remove("update_id") {
+            Some(id) => Some(serde_json::from_value(id).map_err(de::Error::custom)?),
+            None => None,
+        };
+        // Look up the supported types first, the map is sorted by key
+        // so an unrelated field may come before the update type.
+        let kind = match UPDATE_KINDS.iter().find_map(|k| map.remove_entry(*k)) {
+            Some(entry) => serde_json::from_value(Value::Object([entry].into_iter().collect()))
+                .map_err(de::Error::custom)?,
+            None => match map.into_iter().next() {
+                Some((key, value)) => InboundData::Unknown { key, value },
+                None => InboundData::Unknown {
+                    key: String::new(),
+                    value: Value::Null,
+                },
+            },
+        };
+
+        Ok(Update { update_id, kind })
+    }
+}
+
+impl Deref for Update {
+    type Target = InboundData;
+
+    fn deref(&self) -> &InboundData {
+        &self.kind
+    }
 }
 
 pub fn inbound(s: String) -> Result<InboundData, String> {
+    inbound_update(s).map(|update| update.kind)
+}
+
+/// Like [`inbound`], keeping the `update_id` of the update.
+pub fn inbound_update(s: String) -> Result<Update, String> {
     #[cfg(debug_assertions)]
-    return serde_json::from_str::<InboundData>(&s)
+    return serde_json::from_str::<Update>(&s)
         .map_err(|e| format!("Parsing Telegram Webhook payload failed: {}", e.to_string()));
 
     #[cfg(not(debug_assertions))]
-    serde_json::from_str::<InboundData>(&s)
+    serde_json::from_str::<Update>(&s)
         .map_err(|_| format!("Parsing Telegram Webhook payload failed: {}", s))
 }
 
@@ -262,6 +347,13 @@ impl InboundData {
             _ => Err("as_chat_join_request failed".to_string()),
         }
     }
+
+    pub fn as_callback_query(&self) -> Result<&CallbackQuery, String> {
+        match self {
+            InboundData::CallbackQuery(ref c) => Ok(c),
+            _ => Err("as_callback_query failed".to_string()),
+        }
+    }
 }
 
 pub mod outbound {
@@ -270,9 +362,13 @@ pub mod outbound {
     use serde::Serialize;
     use serde_json::{json, Value};
 
+    /// The maximum size of the callback data of an inline keyboard button in bytes.
+    pub const MAX_CALLBACK_DATA_LEN: usize = 64;
+
     #[derive(Serialize)]
     pub struct OutboundData {
-        chat_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        chat_id: Option<String>,
 
         #[serde(flatten)]
         extra: HashMap<String, Value>,
@@ -325,7 +421,7 @@ pub mod outbound {
     /// ```
     pub fn message<C: Into<ChatId>, T: Into<String>>(chat_id: C, message: T) -> OutboundData {
         OutboundData {
-            chat_id: chat_id.into().to_string(),
+            chat_id: Some(chat_id.into().to_string()),
             extra: [("text".to_string(), json!(message.into()))]
                 .into_iter()
                 .collect(),
@@ -347,7 +443,7 @@ pub mod outbound {
         message: T,
     ) -> OutboundData {
         OutboundData {
-            chat_id: chat_id.into().to_string(),
+            chat_id: Some(chat_id.into().to_string()),
             extra: [
                 ("message_id".to_string(), json!(message_id.into())),
                 ("text".to_string(), json!(message.into())),
@@ -367,13 +463,263 @@ pub mod outbound {
     /// ```
     pub fn ban<C: Into<ChatId>, T: Into<String>>(chat_id: C, user_id: T) -> OutboundData {
         OutboundData {
-            chat_id: chat_id.into().to_string(),
+            chat_id: Some(chat_id.into().to_string()),
             extra: [("user_id".to_string(), json!(user_id.into()))]
                 .into_iter()
                 .collect(),
         }
     }
 
+    /// Answer a callback query sent from an inline keyboard,
+    /// the user sees a progress bar until the query is answered.
+    /// Click [here](https://core.telegram.org/bots/api#answercallbackquery) for other fields.
+    ///
+    /// eg.
+    /// ```rust
+    /// # use flows_connector_dsi::telegram::{outbound, CallbackQuery};
+    /// # fn answer(query: &CallbackQuery) -> Result<String, String> {
+    /// outbound::answer_callback_query(&query.id)
+    ///     .text("Approved")
+    ///     .build()
+    /// # }
+    /// ```
+    pub fn answer_callback_query<T: Into<String>>(callback_query_id: T) -> OutboundData {
+        OutboundData {
+            chat_id: None,
+            extra: [(
+                "callback_query_id".to_string(),
+                json!(callback_query_id.into()),
+            )]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct InlineKeyboardButton {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        callback_data: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        switch_inline_query: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        switch_inline_query_current_chat: Option<String>,
+    }
+
+    impl InlineKeyboardButton {
+        fn new(text: String) -> Self {
+            Self {
+                text,
+                url: None,
+                callback_data: None,
+                switch_inline_query: None,
+                switch_inline_query_current_chat: None,
+            }
+        }
+
+        /// A button opening the URL.
+        pub fn url<T: Into<String>, U: Into<String>>(text: T, url: U) -> Self {
+            Self {
+                url: Some(url.into()),
+                ..Self::new(text.into())
+            }
+        }
+
+        /// A button sending a callback query with the data,
+        /// at most [`MAX_CALLBACK_DATA_LEN`] bytes.
+        pub fn callback<T: Into<String>, D: Into<String>>(text: T, data: D) -> Self {
+            Self {
+                callback_data: Some(data.into()),
+                ..Self::new(text.into())
+            }
+        }
+
+        /// A button prompting the user to select a chat,
+        /// and inserting the bot's username and the query in its input field.
+        pub fn switch_inline<T: Into<String>, Q: Into<String>>(text: T, query: Q) -> Self {
+            Self {
+                switch_inline_query: Some(query.into()),
+                ..Self::new(text.into())
+            }
+        }
+
+        /// A button inserting the bot's username and the query
+        /// in the input field of the current chat.
+        pub fn switch_inline_current_chat<T: Into<String>, Q: Into<String>>(
+            text: T,
+            query: Q,
+        ) -> Self {
+            Self {
+                switch_inline_query_current_chat: Some(query.into()),
+                ..Self::new(text.into())
+            }
+        }
+    }
+
+    /// Buttons shown right under the message.
+    #[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+    pub struct InlineKeyboard {
+        inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
+    }
+
+    impl InlineKeyboard {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Add a row of buttons.
+        pub fn row(mut self, buttons: Vec<InlineKeyboardButton>) -> Self {
+            self.inline_keyboard.push(buttons);
+            self
+        }
+    }
+
+    #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct KeyboardButton {
+        text: String,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        request_contact: bool,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        request_location: bool,
+    }
+
+    impl KeyboardButton {
+        /// A button sending its text as a message.
+        pub fn new<T: Into<String>>(text: T) -> Self {
+            Self {
+                text: text.into(),
+                request_contact: false,
+                request_location: false,
+            }
+        }
+
+        /// Send the user's phone number instead.
+        pub fn request_contact(mut self) -> Self {
+            self.request_contact = true;
+            self
+        }
+
+        /// Send the user's current location instead.
+        pub fn request_location(mut self) -> Self {
+            self.request_location = true;
+            self
+        }
+    }
+
+    impl From<&str> for KeyboardButton {
+        fn from(text: &str) -> Self {
+            Self::new(text)
+        }
+    }
+
+    /// A custom keyboard replacing the user's keyboard.
+    #[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+    pub struct ReplyKeyboard {
+        keyboard: Vec<Vec<KeyboardButton>>,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        resize_keyboard: bool,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        one_time_keyboard: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        input_field_placeholder: Option<String>,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        selective: bool,
+    }
+
+    impl ReplyKeyboard {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Add a row of buttons.
+        pub fn row<B: Into<KeyboardButton>>(mut self, buttons: Vec<B>) -> Self {
+            self.keyboard
+                .push(buttons.into_iter().map(Into::into).collect());
+            self
+        }
+
+        /// Fit the keyboard height to its buttons.
+        pub fn resize(mut self) -> Self {
+            self.resize_keyboard = true;
+            self
+        }
+
+        /// Hide the keyboard once a button is pressed.
+        pub fn one_time(mut self) -> Self {
+            self.one_time_keyboard = true;
+            self
+        }
+
+        /// Placeholder shown in the input field while the keyboard is active.
+        pub fn placeholder<T: Into<String>>(mut self, placeholder: T) -> Self {
+            self.input_field_placeholder = Some(placeholder.into());
+            self
+        }
+
+        /// Only show the keyboard to the mentioned users and the sender of the replied message.
+        pub fn selective(mut self) -> Self {
+            self.selective = true;
+            self
+        }
+    }
+
+    /// Remove the custom keyboard of the user.
+    #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+    pub struct KeyboardRemove {
+        remove_keyboard: bool,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        selective: bool,
+    }
+
+    impl KeyboardRemove {
+        pub fn new() -> Self {
+            Self {
+                remove_keyboard: true,
+                selective: false,
+            }
+        }
+
+        /// Only remove the keyboard of the mentioned users and the sender of the replied message.
+        pub fn selective(mut self) -> Self {
+            self.selective = true;
+            self
+        }
+    }
+
+    impl Default for KeyboardRemove {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+    #[serde(untagged)]
+    pub enum ReplyMarkup {
+        InlineKeyboard(InlineKeyboard),
+        ReplyKeyboard(ReplyKeyboard),
+        KeyboardRemove(KeyboardRemove),
+    }
+
+    impl From<InlineKeyboard> for ReplyMarkup {
+        fn from(k: InlineKeyboard) -> Self {
+            Self::InlineKeyboard(k)
+        }
+    }
+
+    impl From<ReplyKeyboard> for ReplyMarkup {
+        fn from(k: ReplyKeyboard) -> Self {
+            Self::ReplyKeyboard(k)
+        }
+    }
+
+    impl From<KeyboardRemove> for ReplyMarkup {
+        fn from(k: KeyboardRemove) -> Self {
+            Self::KeyboardRemove(k)
+        }
+    }
+
     impl OutboundData {
         /// Build outbound JSON data.
         pub fn build(self) -> Result<String, String> {
@@ -381,6 +727,25 @@ pub mod outbound {
                 return Err("OutboundData build failed: Too few fields".to_string());
             }
 
+            if let Some(Value::Array(rows)) = self
+                .extra
+                .get("reply_markup")
+                .and_then(|m| m.get("inline_keyboard"))
+            {
+                let invalid = rows
+                    .iter()
+                    .filter_map(Value::as_array)
+                    .flatten()
+                    .filter_map(|b| b.get("callback_data").and_then(Value::as_str))
+                    .find(|d| d.is_empty() || d.len() > MAX_CALLBACK_DATA_LEN);
+                if let Some(data) = invalid {
+                    return Err(format!(
+                        "OutboundData build failed: callback data {:?} is not 1-{} bytes",
+                        data, MAX_CALLBACK_DATA_LEN
+                    ));
+                }
+            }
+
             serde_json::to_string(&self)
                 .map_err(|e| format!("OutboundData build failed: {}", e.to_string()))
         }
@@ -398,6 +763,40 @@ pub mod outbound {
             self
         }
 
+        /// Attach an inline keyboard, a custom reply keyboard
+        /// or remove the reply keyboard.
+        ///
+        /// eg.
+        /// ```rust
+        /// # use flows_connector_dsi::telegram::{outbound::{self, *}, Message};
+        /// # fn ask(message: &Message) -> Result<String, String> {
+        /// outbound::message(message.chat.id, "Deploy to production?")
+        ///     .reply_markup(InlineKeyboard::new().row(vec![
+        ///         InlineKeyboardButton::callback("Yes", "deploy:yes"),
+        ///         InlineKeyboardButton::callback("No", "deploy:no"),
+        ///     ]))
+        ///     .build()
+        /// # }
+        /// ```
+        pub fn reply_markup<M: Into<ReplyMarkup>>(mut self, markup: M) -> Self {
+            self.extra
+                .insert("reply_markup".to_string(), json!(markup.into()));
+            self
+        }
+
+        /// Text of the notification, only used on answer callback query.
+        pub fn text<T: Into<String>>(mut self, text: T) -> Self {
+            self.extra.insert("text".to_string(), json!(text.into()));
+            self
+        }
+
+        /// Show an alert instead of a notification at the top of the chat screen,
+        /// only used on answer callback query.
+        pub fn show_alert(mut self) -> Self {
+            self.extra.insert("show_alert".to_string(), json!(true));
+            self
+        }
+
         /// Add extra field.
         pub fn field<K: Into<String>>(mut self, name: K, value: Value) -> Self {
             self.extra.insert(name.into(), value);
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod github_jira_tests {
+    use serde_json::{json, Value};
+
+    use crate::github;
+    use crate::github_jira::*;
+
+    #[test]
+    fn scan_test() {
+        let scanner = IssueKeyScanner::new();
+
+        assert_eq!(
+            scanner.scan(
+                "[HT-21] fix login (HT-21, OPS_2-7), see https://x.atlassian.net/browse/WEB-3."
+            ),
+            vec!["HT-21", "OPS_2-7", "WEB-3"]
+        );
+        assert!(scanner
+            .scan("XHT-1a HT-01 H-1 ht-1 HT- HT-1_2 abcHT-1")
+            .is_empty());
+        assert_eq!(scanner.scan("修复HT-1，HT-2"), vec!["HT-1", "HT-2"]);
+        assert_eq!(
+            scanner.scan_ref("refs/heads/feature/HT-21-login"),
+            vec!["HT-21"]
+        );
+
+        let scanner = IssueKeyScanner::new().allow("HT").allow("OPS");
+        assert_eq!(
+            scanner.scan("HT-1 WEB-2 UTF-8 OPS-3 HTX-4"),
+            vec!["HT-1", "OPS-3"]
+        );
+    }
+
+    #[test]
+    fn scan_github_test() {
+        let mut v: Value =
+            serde_json::from_str(include_str!("github_pull_request_review.json")).unwrap();
+        v["pull_request"]["title"] = json!("HT-21: Update the README");
+        v["pull_request"]["body"] = json!("Also closes HT-22 and HT-21.");
+        v["pull_request"]["head"]["ref"] = json!("HT-23-readme");
+        let d = github::inbound(v.to_string()).unwrap();
+        let scanner = IssueKeyScanner::new().allow("HT");
+
+        let keys = scanner.scan_pull_request(d.get_pull_request().unwrap());
+        assert_eq!(keys, vec!["HT-21", "HT-22", "HT-23"]);
+
+        let mut v: Value = serde_json::from_str(include_str!("github_push.json")).unwrap();
+        v["commits"][0]["message"] = json!("HT-24 fix typo\n\nRefs WEB-1");
+        let d = github::inbound(v.to_string()).unwrap();
+        let commits = d.commits.as_ref().unwrap();
+
+        assert_eq!(scanner.scan_commit(&commits[0]), vec!["HT-24"]);
+        assert_eq!(scanner.scan_commits(commits), vec!["HT-24"]);
+
+        let payloads = comment_on_issues(&keys[..1], "Mentioned in a pull request")
+            .into_iter()
+            .map(|d| serde_json::from_str::<Value>(&d.build().unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            payloads,
+            vec![json!({
+                "issue_key": "HT-21",
+                "comment": "Mentioned in a pull request"
+            })]
+        );
+    }
+}
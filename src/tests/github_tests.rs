@@ -23,6 +23,49 @@ mod github_tests {
         );
     }
 
+    #[test]
+    fn discussion_answered_test() {
+        let s = include_str!("github_discussion_answered.json").to_string();
+        let d = inbound(s).unwrap();
+
+        assert_eq!(
+            d.get_discussion_action().unwrap(),
+            DiscussionAction::Answered
+        );
+        assert_eq!(
+            d.get_discussion().unwrap().answer_html_url.as_ref(),
+            Some(&d.get_answer().unwrap().html_url)
+        );
+    }
+
+    #[test]
+    fn discussion_category_changed_test() {
+        let mut v: Value =
+            serde_json::from_str(include_str!("github_discussion_answered.json")).unwrap();
+        v["action"] = json!("category_changed");
+        v["changes"] = json!({ "category": { "from": v["discussion"]["category"].clone() } });
+        v.as_object_mut().unwrap().remove("answer");
+        let d = inbound(v.to_string()).unwrap();
+
+        assert_eq!(
+            d.get_discussion_action().unwrap(),
+            DiscussionAction::CategoryChanged
+        );
+        assert_eq!(
+            d.get_discussion_changes()
+                .unwrap()
+                .category
+                .unwrap()
+                .from
+                .slug
+                .as_deref(),
+            Some("q-a")
+        );
+
+        let s = include_str!("github_discussion_comment.json").to_string();
+        assert!(inbound(s).unwrap().get_discussion_action().is_err());
+    }
+
     #[test]
     fn fork_test() {
         let s = include_str!("github_fork.json").to_string();
@@ -54,6 +97,84 @@ mod github_tests {
         );
     }
 
+    #[test]
+    fn reactions_test() {
+        let s = include_str!("github_pr_review_comment.json").to_string();
+        let d = inbound(s).unwrap();
+        let reactions = d.get_comment().unwrap().reactions.as_ref().unwrap();
+
+        assert_eq!(reactions.plus_one, 0);
+        assert_eq!(reactions.total_count, 0);
+    }
+
+    #[test]
+    fn gollum_test() {
+        let s = include_str!("github_gollum.json").to_string();
+        let d = inbound(s).unwrap();
+        let pages = d.get_pages().unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].action, WikiPageAction::Edited);
+        assert_eq!(pages[1].title, "Getting Started");
+    }
+
+    #[test]
+    fn page_build_test() {
+        let s = include_str!("github_page_build.json").to_string();
+        let d = inbound(s).unwrap();
+        let build = d.get_page_build().unwrap();
+
+        assert_eq!(build.status, "errored");
+        assert_eq!(build.error.message.as_deref(), Some("Page build failed."));
+        assert_eq!(build.duration, 2104);
+    }
+
+    #[test]
+    fn ping_test() {
+        let s = include_str!("github_ping.json").to_string();
+        let d = inbound(s).unwrap();
+        let ping = d.get_ping().unwrap();
+        let hook = ping.hook;
+
+        assert!(d.is_ping());
+        assert_eq!(ping.hook_id, hook.id);
+        assert!(!ping.zen.is_empty());
+        assert_eq!(hook.config.content_type.as_deref(), Some("json"));
+
+        assert!(hook
+            .check_events(&["issues", "pull_request", "push"])
+            .is_ok());
+        assert_eq!(
+            hook.check_events(&["push", "release"]),
+            HookEventsReport {
+                missing: vec!["release".to_string()],
+                unexpected: vec!["issues".to_string(), "pull_request".to_string()],
+            }
+        );
+        assert!(hook.check_events(&["*"]).unexpected.is_empty());
+
+        let mut v: Value = serde_json::from_str(include_str!("github_ping.json")).unwrap();
+        v["hook"]["events"] = json!(["*"]);
+        let d = inbound(v.to_string()).unwrap();
+        let hook = d.get_ping().unwrap().hook;
+
+        assert!(hook.check_events(&["push"]).is_ok());
+        assert!(hook.check_events(&["*"]).is_ok());
+
+        let s = include_str!("github_issue_comment.json").to_string();
+        assert!(!inbound(s).unwrap().is_ping());
+
+        // The `meta` event sent when the hook is deleted has no `zen`.
+        let mut v: Value = serde_json::from_str(include_str!("github_ping.json")).unwrap();
+        v.as_object_mut().unwrap().remove("zen");
+        v["action"] = json!("deleted");
+        let d = inbound(v.to_string()).unwrap();
+
+        assert!(!d.is_ping());
+        assert!(d.get_ping().is_err());
+        assert_eq!(d.get_hook_id().unwrap(), d.get_hook().unwrap().id);
+    }
+
     #[test]
     fn label_test() {
         let s = include_str!("github_label.json").to_string();
@@ -61,6 +182,76 @@ mod github_tests {
         println!("{:#?}", inbound(s).unwrap().get_label().unwrap());
     }
 
+    #[test]
+    fn merge_group_test() {
+        let s = include_str!("github_merge_group.json").to_string();
+        let d = inbound(s).unwrap();
+        let g = d.get_merge_group().unwrap();
+
+        assert_eq!(
+            d.get_merge_group_action().unwrap(),
+            MergeGroupAction::ChecksRequested
+        );
+        assert_eq!(g.base_ref, "refs/heads/main");
+        assert_eq!(g.head_commit.id, g.head_sha);
+        assert!(d.get_merge_group_destroyed_reason().is_err());
+    }
+
+    #[test]
+    fn branch_protection_rule_test() {
+        let s = include_str!("github_branch_protection_rule.json").to_string();
+        let d = inbound(s).unwrap();
+
+        assert_eq!(d.get_branch_protection_rule().unwrap().name, "main");
+        assert_eq!(
+            d.get_weakened_branch_protections().unwrap(),
+            vec![
+                "allow_force_pushes_enforcement_level",
+                "dismiss_stale_reviews_on_push",
+                "required_approving_review_count",
+                "required_status_checks",
+            ]
+        );
+
+        let mut v: Value =
+            serde_json::from_str(include_str!("github_branch_protection_rule.json")).unwrap();
+        v["rule"]["allow_force_pushes_enforcement_level"] = json!("bypass_allowed");
+        let d = inbound(v.to_string()).unwrap();
+
+        assert_eq!(
+            d.get_branch_protection_rule()
+                .unwrap()
+                .allow_force_pushes_enforcement_level,
+            EnforcementLevel::Unknown
+        );
+        assert!(!d
+            .get_weakened_branch_protections()
+            .unwrap()
+            .contains(&"allow_force_pushes_enforcement_level"));
+
+        let mut v: Value =
+            serde_json::from_str(include_str!("github_branch_protection_rule.json")).unwrap();
+        v["changes"]["merge_queue_enforcement_level"] = json!({ "from": "everyone" });
+        v["changes"]["required_deployments_enforcement_level"] = json!({ "from": "off" });
+        v["changes"]["authorized_dismissal_actors_only"] = json!({ "from": true });
+        v["rule"]["require_last_push_approval"] = json!(false);
+        v["changes"]["require_last_push_approval"] = json!({ "from": true });
+        let weakened = inbound(v.to_string())
+            .unwrap()
+            .get_weakened_branch_protections()
+            .unwrap();
+
+        assert!(weakened.contains(&"merge_queue_enforcement_level"));
+        assert!(weakened.contains(&"authorized_dismissal_actors_only"));
+        assert!(weakened.contains(&"require_last_push_approval"));
+        assert!(!weakened.contains(&"required_deployments_enforcement_level"));
+
+        assert!(EnforcementLevel::Off < EnforcementLevel::Everyone);
+        assert!(EnforcementLevel::Unknown
+            .partial_cmp(&EnforcementLevel::Off)
+            .is_none());
+    }
+
     #[test]
     fn marketplace_purchase_test() {
         let s = include_str!("github_marketplace_purchase.json").to_string();
@@ -71,6 +262,95 @@ mod github_tests {
         );
     }
 
+    #[test]
+    fn milestone_test() {
+        let s = include_str!("github_milestone.json").to_string();
+        let m = inbound(s).unwrap();
+        let m = m.get_milestone().unwrap();
+
+        assert_eq!(m.number, 1);
+        assert_eq!(m.due_on.as_deref(), Some("2019-05-23T07:00:00Z"));
+
+        let s = include_str!("github_issue_comment.json").to_string();
+        let d = inbound(s).unwrap();
+        let m = d.get_issue().unwrap().milestone.as_ref().unwrap();
+        assert_eq!((m.number, m.title.as_str()), (1, "v1.0"));
+
+        let milestone: Value = serde_json::from_str(include_str!("github_milestone.json")).unwrap();
+        let mut v: Value =
+            serde_json::from_str(include_str!("github_pull_request_review.json")).unwrap();
+        v["pull_request"]["milestone"] = milestone["milestone"].clone();
+        let d = inbound(v.to_string()).unwrap();
+        let m = d.get_pull_request().unwrap().milestone.as_ref().unwrap();
+        assert_eq!(m.number, 1);
+        assert_eq!(m.due_on.as_deref(), Some("2019-05-23T07:00:00Z"));
+    }
+
+    #[test]
+    fn marketplace_purchase_changed_test() {
+        let s = include_str!("github_marketplace_purchase_changed.json").to_string();
+        let d = inbound(s).unwrap();
+
+        assert_eq!(
+            d.get_marketplace_action().unwrap(),
+            MarketplaceAction::Changed
+        );
+        assert_eq!(d.get_previous_marketplace_purchase().unwrap().plan.id, 435);
+        assert_eq!(d.get_marketplace_price_delta().unwrap(), 2000 * 3 - 1000);
+    }
+
+    #[test]
+    fn sponsorship_test() {
+        let s = include_str!("github_sponsorship.json").to_string();
+        let d = inbound(s).unwrap();
+
+        assert_eq!(
+            d.get_sponsorship_action().unwrap(),
+            SponsorshipAction::TierChanged
+        );
+        assert_eq!(
+            d.get_sponsorship().unwrap().privacy_level,
+            PrivacyLevel::Public
+        );
+        assert_eq!(d.get_sponsorship_price_delta().unwrap(), 500);
+    }
+
+    #[test]
+    fn registry_package_test() {
+        let s = include_str!("github_registry_package.json").to_string();
+        let d = inbound(s).unwrap();
+        let p = d.get_registry_package().unwrap();
+        let v = p.package_version.as_ref().unwrap();
+
+        assert_eq!(p.ecosystem, "CONTAINER");
+        assert_eq!(v.tags(), vec!["v1.0.0"]);
+        assert_eq!(v.digest(), Some(v.version.as_str()));
+        assert_eq!(
+            v.installation_command.as_deref(),
+            Some("docker pull ghcr.io/ho-229/hello-world:v1.0.0")
+        );
+    }
+
+    #[test]
+    fn package_test() {
+        let s = include_str!("github_package.json").to_string();
+        let d = inbound(s).unwrap();
+        let p = d.get_package().unwrap();
+        let v = p.package_version.as_ref().unwrap();
+
+        assert_eq!(d.get_action().unwrap(), "published");
+        assert_eq!(p.ecosystem, "npm");
+        assert_eq!(p.registry.as_ref().unwrap().vendor, "GitHub Inc");
+        assert_eq!(v.version, "1.0.0");
+        assert_eq!(v.tags(), vec!["v1.0.0"]);
+        assert!(v.digest().is_none());
+        assert_eq!(
+            v.installation_command.as_deref(),
+            Some("npm install @ho-229/hello-world@1.0.0")
+        );
+        assert!(d.get_registry_package().is_err());
+    }
+
     #[test]
     fn pull_request_test() {
         let s = include_str!("github_pull_request_review.json").to_string();
@@ -83,6 +363,61 @@ mod github_tests {
         );
     }
 
+    #[test]
+    fn pull_request_helpers_test() {
+        let s = include_str!("github_pr_review_comment.json").to_string();
+        let d = inbound(s).unwrap();
+        let pr = d.get_pull_request().unwrap();
+
+        assert!(!pr.is_merged());
+        assert!(!pr.is_cross_repo());
+        assert_eq!(pr.is_ready_for_review(), pr.draft != Some(true));
+
+        let mut v: Value =
+            serde_json::from_str(include_str!("github_pr_review_comment.json")).unwrap();
+        v["pull_request"]["head"]["repo"]["full_name"] = json!("someone/fork");
+        let d = inbound(v.to_string()).unwrap();
+        assert!(d.get_pull_request().unwrap().is_cross_repo());
+
+        let mut v: Value =
+            serde_json::from_str(include_str!("github_pull_request_review.json")).unwrap();
+        v["pull_request"]["additions"] = json!(40);
+        v["pull_request"]["deletions"] = json!(20);
+        v["pull_request"]["labels"] = json!([
+            { "name": "bug", "color": "d73a4a", "default": true },
+            { "name": "size/XS", "color": "ededed", "default": false }
+        ]);
+        let d = inbound(v.to_string()).unwrap();
+        let pr = d.get_pull_request().unwrap();
+
+        assert_eq!(
+            pr.size(&SizeThresholds::default()),
+            Some(PullRequestSize::M)
+        );
+        assert_eq!(
+            pr.size(&SizeThresholds {
+                xs: 10,
+                s: 20,
+                m: 30,
+                l: 50
+            }),
+            Some(PullRequestSize::XL)
+        );
+
+        let d = pr
+            .size_label_update(&SizeThresholds::default())
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "issue_number": pr.number,
+                "labels": ["bug", "size/M"]
+            })
+        );
+    }
+
     #[test]
     fn release_test() {
         let s = include_str!("github_release.json").to_string();
@@ -113,7 +448,7 @@ mod github_tests {
     fn outbound_test() {
         let d = outbound::create_issue("issue title")
             .labels(vec!["test"])
-            .milestone(json!(1))
+            .milestone(Some(1))
             .assignees(vec!["ho-229"])
             .body("message")
             .build()
@@ -130,4 +465,337 @@ mod github_tests {
             })
         );
     }
+
+    #[test]
+    fn clear_milestone_outbound_test() {
+        let d = outbound::modify_issue(1).milestone(None).build().unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "issue_number": 1,
+                "milestone": null
+            })
+        );
+    }
+
+    #[test]
+    fn commit_status_outbound_test() {
+        let d = outbound::create_commit_status("abc123", outbound::CommitState::Failure)
+            .context("ci/lint")
+            .target_url("https://example.com/build/1")
+            .description("2 lint errors")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "sha": "abc123",
+                "state": "failure",
+                "context": "ci/lint",
+                "target_url": "https://example.com/build/1",
+                "description": "2 lint errors"
+            })
+        );
+
+        assert!(outbound::create_commit_status("abc123", outbound::CommitState::Success)
+            .description("x".repeat(outbound::MAX_STATUS_DESCRIPTION_LEN + 1))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn check_run_outbound_test() {
+        use outbound::{Annotation, AnnotationLevel, CheckConclusion, CheckRunOutput, CheckStatus};
+
+        let d = outbound::create_check_run("lint", "abc123")
+            .conclusion(CheckConclusion::Failure)
+            .output(
+                CheckRunOutput::new("1 error", "Found 1 error")
+                    .text("details")
+                    .annotation(
+                        Annotation::new("src/lib.rs", 3, 4, AnnotationLevel::Warning, "unused")
+                            .title("dead code"),
+                    ),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "name": "lint",
+                "head_sha": "abc123",
+                "status": "completed",
+                "conclusion": "failure",
+                "output": {
+                    "title": "1 error",
+                    "summary": "Found 1 error",
+                    "text": "details",
+                    "annotations": [{
+                        "path": "src/lib.rs",
+                        "start_line": 3,
+                        "end_line": 4,
+                        "annotation_level": "warning",
+                        "message": "unused",
+                        "title": "dead code"
+                    }]
+                }
+            })
+        );
+
+        assert!(outbound::create_check_run("lint", "abc123")
+            .status(CheckStatus::InProgress)
+            .build()
+            .is_ok());
+        assert!(outbound::create_check_run("lint", "abc123")
+            .status(CheckStatus::Completed)
+            .build()
+            .is_err());
+        assert!(outbound::create_check_run("lint", "abc123")
+            .conclusion(CheckConclusion::Success)
+            .status(CheckStatus::InProgress)
+            .build()
+            .is_err());
+        assert!(outbound::create_check_run("lint", "abc123")
+            .conclusion(CheckConclusion::Success)
+            .output(
+                CheckRunOutput::new("ok", "ok")
+                    .text("x".repeat(outbound::MAX_CHECK_RUN_OUTPUT_LEN + 1))
+            )
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn reaction_outbound_test() {
+        use outbound::{ReactionContent, ReactionTarget};
+
+        let d = outbound::create_reaction(ReactionTarget::Issue(1), ReactionContent::PlusOne)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "issue_number": 1,
+                "content": "+1"
+            })
+        );
+
+        let d = outbound::create_reaction(
+            ReactionTarget::DiscussionComment("DC_kwDOG2qXVs4AQ5xP".to_string()),
+            ReactionContent::Rocket,
+        )
+        .build()
+        .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "discussion_comment_node_id": "DC_kwDOG2qXVs4AQ5xP",
+                "content": "rocket"
+            })
+        );
+    }
+
+    #[test]
+    fn summary_escape_test() {
+        use summary::{escape, Flavor};
+
+        assert_eq!(escape("a_b*c.d", Flavor::CommonMark), "a\\_b\\*c.d");
+        assert_eq!(
+            escape("v1.0 (beta)!", Flavor::TelegramMarkdownV2),
+            "v1\\.0 \\(beta\\)\\!"
+        );
+        assert_eq!(escape("~~x~~ > y", Flavor::Discord), "\\~\\~x\\~\\~ \\> y");
+        assert_eq!(
+            escape("- a\n  + b\n1. c\n2) d\n-e 3.5", Flavor::CommonMark),
+            "\\- a\n  \\+ b\n1\\. c\n2\\) d\n-e 3.5"
+        );
+        assert_eq!(escape("10. x", Flavor::Discord), "10\\. x");
+    }
+
+    #[test]
+    fn summary_push_test() {
+        use summary::{render, Flavor};
+
+        let s = include_str!("github_push.json").to_string();
+        let d = inbound(s).unwrap();
+
+        let summary = render(&d, Flavor::CommonMark).unwrap();
+        assert!(!summary.one_line.contains('\n'));
+        assert!(summary.one_line.contains("pushed [1 commit]("));
+        assert!(summary.one_line.ends_with("to `test`"));
+        assert!(summary.multi_line.starts_with(&summary.one_line));
+
+        let summary = render(&d, Flavor::TelegramMarkdownV2).unwrap();
+        assert!(summary
+            .one_line
+            .starts_with("\\[ho\\-229/hello\\-world\\] *ho\\-229*"));
+
+        let mut v: Value = serde_json::from_str(include_str!("github_push.json")).unwrap();
+        v["forced"] = json!(true);
+        let d = inbound(v.to_string()).unwrap();
+        let summary = render(&d, Flavor::CommonMark).unwrap();
+        assert!(summary.one_line.contains("force-pushed [1 commit]("));
+
+        v["commits"] = json!([]);
+        let d = inbound(v.to_string()).unwrap();
+        let summary = render(&d, Flavor::CommonMark).unwrap();
+        assert!(summary.one_line.ends_with("force-pushed `test`"));
+        assert_eq!(summary.multi_line, summary.one_line);
+
+        v["deleted"] = json!(true);
+        let d = inbound(v.to_string()).unwrap();
+        let summary = render(&d, Flavor::CommonMark).unwrap();
+        assert!(summary.one_line.ends_with("deleted `test`"));
+        assert!(!summary.one_line.contains("commit"));
+    }
+
+    #[test]
+    fn summary_issue_opened_test() {
+        let mut v: Value = serde_json::from_str(include_str!("github_issue_comment.json")).unwrap();
+        v["action"] = json!("opened");
+        v.as_object_mut().unwrap().remove("comment");
+        let d = inbound(v.to_string()).unwrap();
+
+        let summary = summary::render(&d, summary::Flavor::Discord).unwrap();
+        assert!(summary
+            .one_line
+            .contains("opened issue [\\#1 Spelling error in the README file]("));
+    }
+
+    #[test]
+    fn summary_pull_request_merged_test() {
+        use summary::{render, Flavor};
+
+        let mut v: Value =
+            serde_json::from_str(include_str!("github_pull_request_review.json")).unwrap();
+        v["action"] = json!("closed");
+        v.as_object_mut().unwrap().remove("review");
+        let err = render(&inbound(v.to_string()).unwrap(), Flavor::TelegramMarkdownV2);
+        assert!(err.unwrap_err().contains("closed without merge"));
+
+        let pr = &mut v["pull_request"];
+        pr["merged"] = json!(true);
+        pr["additions"] = json!(10);
+        pr["deletions"] = json!(2);
+        pr["changed_files"] = json!(1);
+        pr["commits"] = json!(3);
+        let summary = render(&inbound(v.to_string()).unwrap(), Flavor::TelegramMarkdownV2).unwrap();
+
+        assert_eq!(
+            summary.one_line,
+            concat!(
+                r"\[Codertocat/Hello\-World\] *Codertocat* merged pull request ",
+                r"[\#2 Update the README with new information\.]",
+                r"(https://github.com/Codertocat/Hello-World/pull/2) into `master`"
+            )
+        );
+        assert_eq!(
+            summary.multi_line,
+            format!(
+                "{}\n\n{}\n{}",
+                summary.one_line, r"\+10 \-2 in 1 file\(s\)", r"3 commit\(s\)"
+            )
+        );
+    }
+
+    #[test]
+    fn summary_release_published_test() {
+        use summary::{render, Flavor};
+
+        let s = include_str!("github_release.json").to_string();
+        let summary = render(&inbound(s).unwrap(), Flavor::TelegramMarkdownV2).unwrap();
+
+        assert_eq!(
+            summary.one_line,
+            concat!(
+                r"\[Codertocat/Hello\-World\] *Codertocat* published release ",
+                r"[0\.0\.1](https://github.com/Codertocat/Hello-World/releases/tag/0.0.1)"
+            )
+        );
+        assert_eq!(
+            summary.multi_line,
+            format!("{}\n\nTag: `0.0.1`", summary.one_line)
+        );
+
+        let mut v: Value = serde_json::from_str(include_str!("github_release.json")).unwrap();
+        v["release"]["prerelease"] = json!(true);
+        v["release"]["body"] = json!("- Fixed login");
+        let summary = render(&inbound(v.to_string()).unwrap(), Flavor::TelegramMarkdownV2).unwrap();
+
+        assert!(summary.one_line.contains("published prerelease"));
+        assert!(summary.multi_line.ends_with("\n\\- Fixed login"));
+    }
+
+    #[test]
+    fn summary_workflow_job_failed_test() {
+        use summary::{render, Flavor};
+
+        let mut v: Value = serde_json::from_str(include_str!("github_workflow_job.json")).unwrap();
+        v["action"] = json!("completed");
+        v["workflow_job"]["conclusion"] = json!("success");
+        let err = render(&inbound(v.to_string()).unwrap(), Flavor::TelegramMarkdownV2);
+        assert!(err.unwrap_err().contains("job did not fail"));
+
+        v["workflow_job"]["conclusion"] = json!("failure");
+        v["workflow_job"]["steps"][1]["conclusion"] = json!("failure");
+        let summary = render(&inbound(v.to_string()).unwrap(), Flavor::TelegramMarkdownV2).unwrap();
+
+        assert_eq!(
+            summary.one_line,
+            concat!(
+                r"\[ho\-229/hello\-world\] Workflow job ",
+                r"[build](https://github.com/ho-229/hello-world/actions/runs/3072551849/jobs/4964159442) failed"
+            )
+        );
+        assert_eq!(
+            summary.multi_line,
+            format!(
+                "{}\n\n{}",
+                summary.one_line, r"\- Step 2 failed: `Run actions/checkout@v3`"
+            )
+        );
+    }
+
+    #[test]
+    fn summary_unsupported_test() {
+        let s = include_str!("github_label.json").to_string();
+
+        assert!(summary::render(&inbound(s).unwrap(), summary::Flavor::CommonMark).is_err());
+    }
+
+    #[test]
+    fn discussion_outbound_test() {
+        let d = outbound::modify_discussion(90)
+            .body("Updated")
+            .category("DIC_kwAqGA")
+            .answer("DC_kwDOG2qXVs4AQ5xP")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "discussion_number": 90,
+                "body": "Updated",
+                "category_id": "DIC_kwAqGA",
+                "answer_comment_id": "DC_kwDOG2qXVs4AQ5xP"
+            })
+        );
+        assert!(outbound::modify_discussion(90).build().is_err());
+
+        let d = outbound::create_discussion_comment(90, "Thanks!")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "discussion_number": 90,
+                "body": "Thanks!"
+            })
+        );
+    }
 }
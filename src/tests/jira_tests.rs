@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod jira_tests {
+    use serde_json::{json, Value};
+
     use crate::jira::*;
 
     #[test]
@@ -8,12 +10,396 @@ mod jira_tests {
         println!("{:#?}", inbound(s).unwrap().get_issue().unwrap());
     }
 
+    #[test]
+    fn issue_fields_test() {
+        let s = include_str!("jira_issue_created.json").to_string();
+        let d = inbound(s).unwrap();
+        let fields = &d.get_issue().unwrap().fields;
+
+        assert_eq!(fields.issuetype.as_ref().unwrap().name, "Task");
+        assert_eq!(fields.priority.as_ref().unwrap().name, "Medium");
+        assert_eq!(fields.resolution.as_ref().unwrap().name, "Done");
+
+        let status = fields.status.as_ref().unwrap();
+        assert_eq!(status.name, "closed");
+        assert_eq!(status.status_category.as_ref().unwrap().key, "done");
+
+        let mut v: Value = serde_json::from_str(include_str!("jira_issue_created.json")).unwrap();
+        let fields = &mut v["issue"]["fields"];
+        fields["duedate"] = json!("2022-10-31");
+        fields["components"] = json!([{
+            "id": "10000",
+            "name": "Backend",
+            "self": "https://ho229.atlassian.net/rest/api/2/component/10000"
+        }, {
+            "id": "10001",
+            "name": "Frontend"
+        }]);
+        fields["fixVersions"] = json!([{
+            "id": "10001",
+            "name": "v1.0",
+            "archived": false,
+            "released": true,
+            "releaseDate": "2022-10-20"
+        }]);
+        for key in ["status", "priority", "resolution"] {
+            fields[key].as_object_mut().unwrap().remove("self");
+        }
+        fields["parent"] = json!({
+            "id": "10020",
+            "key": "HT-20",
+            "self": "https://ho229.atlassian.net/rest/api/2/issue/10020",
+            "fields": { "summary": "Parent Issue" }
+        });
+        fields["subtasks"] = json!([{
+            "id": "10022",
+            "key": "HT-22",
+            "self": "https://ho229.atlassian.net/rest/api/2/issue/10022"
+        }]);
+        let d = inbound(v.to_string()).unwrap();
+        let fields = &d.get_issue().unwrap().fields;
+
+        assert_eq!(fields.duedate.as_deref(), Some("2022-10-31"));
+        assert_eq!(fields.components[0].name, "Backend");
+        assert!(fields.components[1]._self.is_none());
+        assert!(fields.fix_versions[0].released);
+        assert!(fields.fix_versions[0]._self.is_none());
+        assert!(fields.status.as_ref().unwrap()._self.is_none());
+        assert!(fields.priority.as_ref().unwrap()._self.is_none());
+        assert!(fields.resolution.as_ref().unwrap()._self.is_none());
+        assert_eq!(fields.parent.as_ref().unwrap().key, "HT-20");
+        assert_eq!(fields.subtasks[0].key, "HT-22");
+    }
+
+    #[test]
+    fn custom_field_test() {
+        let mut v: Value = serde_json::from_str(include_str!("jira_issue_created.json")).unwrap();
+        let fields = &mut v["issue"]["fields"];
+        fields["customfield_10016"] = json!(3.5);
+        fields["customfield_10020"] = json!([{
+            "id": 1,
+            "name": "HT Sprint 1",
+            "state": "active",
+            "boardId": 1,
+            "goal": "",
+            "startDate": "2022-10-10T08:00:00.000Z",
+            "endDate": "2022-10-24T08:00:00.000Z"
+        }]);
+        fields["customfield_10030"] = json!({
+            "self": "https://ho229.atlassian.net/rest/api/2/customFieldOption/10021",
+            "value": "High",
+            "id": "10021"
+        });
+        fields["customfield_10031"] = json!("2022-10-31");
+        let d = inbound(v.to_string()).unwrap();
+        let fields = &d.get_issue().unwrap().fields;
+
+        let names = CustomFieldNames::new()
+            .register("story_points", "customfield_10016")
+            .register("sprint", "customfield_10020");
+
+        assert_eq!(
+            fields
+                .get_custom_field_named::<f64>(&names, "story_points")
+                .unwrap(),
+            3.5
+        );
+        let sprints: Vec<Sprint> = fields.get_custom_field_named(&names, "sprint").unwrap();
+        assert_eq!(sprints[0].state, "active");
+        assert_eq!(
+            fields
+                .get_custom_field::<CustomFieldOption>("customfield_10030")
+                .unwrap()
+                .value,
+            "High"
+        );
+        assert_eq!(
+            fields
+                .get_custom_field::<DateField>("customfield_10031")
+                .unwrap()
+                .0
+                .to_string(),
+            "2022-10-31"
+        );
+        assert!(fields.get_custom_field::<f64>("customfield_10001").is_err());
+        assert!(fields.get_custom_field::<f64>("customfield_99999").is_err());
+    }
+
     #[test]
     fn issue_updated_test() {
         let s = include_str!("jira_issue_updated.json").to_string();
         println!("{:#?}", inbound(s).unwrap().get_issue().unwrap());
     }
 
+    #[test]
+    fn partial_user_test() {
+        let s = include_str!("jira_comment_created_by_app.json").to_string();
+        let d = inbound(s).unwrap();
+        let author = &d.get_comment().unwrap().author;
+
+        assert!(author.is_app());
+        assert_eq!(author.name(), "Automation for Jira");
+        assert!(author.time_zone.is_none());
+        let avatars = author.avatar_urls.as_ref().unwrap();
+        assert_eq!(avatars.for_size(16), avatars.x48.as_deref());
+
+        let s = include_str!("jira_issue_updated_restricted_user.json").to_string();
+        let d = inbound(s).unwrap();
+        let fields = &d.get_issue().unwrap().fields;
+
+        let assignee = fields.assignee.as_ref().unwrap();
+        assert_eq!(assignee.active, Some(false));
+        assert_eq!(
+            assignee.account_id.as_deref(),
+            Some("5d53f3cbc6b9320d9ea5bdc2")
+        );
+        assert!(assignee.avatar_urls.is_none());
+        assert!(assignee._self.is_none());
+
+        let reporter = fields.reporter.as_ref().unwrap();
+        assert_eq!(reporter.email_address.as_deref(), Some("ho229@example.com"));
+        let avatars = reporter.avatar_urls.as_ref().unwrap();
+        assert_eq!(avatars.for_size(20), avatars.x24.as_deref());
+        assert_eq!(avatars.for_size(64), avatars.x48.as_deref());
+    }
+
+    #[test]
+    fn webhook_event_test() {
+        let s = include_str!("jira_issue_updated.json").to_string();
+        let d = inbound(s).unwrap();
+
+        assert_eq!(d.webhook_event, JiraEvent::IssueUpdated);
+        assert!(d.webhook_event.is_issue_event());
+        assert_eq!(
+            d.get_issue_event_type().unwrap(),
+            &IssueEventType::IssueUpdated
+        );
+
+        let s = include_str!("jira_comment_created.json").to_string();
+        let d = inbound(s).unwrap();
+
+        assert_eq!(d.webhook_event, JiraEvent::CommentCreated);
+        assert!(d.get_issue_event_type().is_err());
+
+        let e = JiraEvent::from("board_created".to_string());
+        assert_eq!(e, JiraEvent::Unknown("board_created".to_string()));
+        assert_eq!(e.to_string(), "board_created");
+        assert_eq!(JiraEvent::VersionReleased.as_str(), "jira:version_released");
+    }
+
+    #[test]
+    fn changelog_test() {
+        let s = include_str!("jira_issue_updated.json").to_string();
+        let d = inbound(s).unwrap();
+        let changelog = d.get_changelog().unwrap();
+
+        assert_eq!(changelog.labels_added(), vec!["test-label"]);
+        assert!(changelog.labels_removed().is_empty());
+        assert!(changelog.status_change().is_none());
+
+        let mut v: Value = serde_json::from_str(include_str!("jira_issue_updated.json")).unwrap();
+        v["changelog"]["items"] = json!([
+            {
+                "field": "status",
+                "fieldId": "status",
+                "fieldtype": "jira",
+                "from": "10000",
+                "fromString": "To Do",
+                "to": "10001",
+                "toString": "Done"
+            },
+            {
+                "field": "assignee",
+                "fieldId": "assignee",
+                "fieldtype": "jira",
+                "from": null,
+                "fromString": null,
+                "to": "5b10ac8d82e05b22cc7d4ef5",
+                "toString": "Mia Krystof"
+            },
+            {
+                "field": "labels",
+                "fieldId": "labels",
+                "fieldtype": "jira",
+                "from": null,
+                "fromString": "backend urgent",
+                "to": null,
+                "toString": "backend triaged"
+            },
+            {
+                "field": "Sprint",
+                "fieldId": "customfield_10020",
+                "fieldtype": "custom",
+                "from": "",
+                "fromString": "",
+                "to": "1",
+                "toString": "Sprint 1"
+            },
+            {
+                "field": "Link",
+                "fieldtype": "jira",
+                "from": null,
+                "fromString": null,
+                "to": "PROJ-2",
+                "toString": "This issue blocks PROJ-2"
+            }
+        ]);
+        let d = inbound(v.to_string()).unwrap();
+        let changelog = d.get_changelog().unwrap();
+
+        assert_eq!(changelog.status_change(), Some(("To Do", "Done")));
+        assert_eq!(
+            changelog.assignee_change(),
+            Some((None, Some("5b10ac8d82e05b22cc7d4ef5")))
+        );
+        assert_eq!(changelog.labels_added(), vec!["triaged"]);
+        assert_eq!(changelog.labels_removed(), vec!["urgent"]);
+
+        let sprint = changelog.field_change(ChangeLogField::Sprint).unwrap();
+        assert_eq!(sprint.to_string.as_deref(), Some("Sprint 1"));
+        assert!(changelog.field_change("customfield_10020").is_some());
+        assert!(changelog.field_change("Link").is_some());
+        assert!(changelog.field_change(ChangeLogField::Priority).is_none());
+    }
+
+    #[test]
+    fn adf_render_test() {
+        let d: adf::Document = serde_json::from_str(include_str!("jira_adf.json")).unwrap();
+
+        assert_eq!(
+            d.to_plain_text(),
+            "Steps\n\nHello @ho-229, see the docs and run cargo test\nIt is important\n\n\
+             1. Build\n2. Test\n  - unit\n\nfn main() {}"
+        );
+        assert_eq!(
+            d.to_markdown(),
+            "## Steps\n\nHello @ho-229, see [the docs](https://flows.network) and run `cargo test`\\\n\
+             It is **important**\n\n1. Build\n2. Test\n   - unit\n\n```rust\nfn main() {}\n```"
+        );
+    }
+
+    #[test]
+    fn adf_from_markdown_test() {
+        let markdown = "## Steps\n\nSee [the docs](https://flows.network), run `cargo test`\\\n\
+                        It is **very *important*** and ~~easy~~ for snake_case\n\n\
+                        1. Build\n2. Test\n   - unit\n\n\
+                        > quoted\n\n---\n\n```rust\nfn main() {}\n```";
+        let d = adf::Document::from_markdown(markdown);
+
+        assert_eq!(d.to_markdown(), markdown);
+        assert_eq!(
+            serde_json::to_value(adf::Document::from_markdown("a **b**")).unwrap(),
+            json!({
+                "version": 1,
+                "type": "doc",
+                "content": [{
+                    "type": "paragraph",
+                    "content": [
+                        { "type": "text", "text": "a " },
+                        { "type": "text", "text": "b", "marks": [{ "type": "strong" }] }
+                    ]
+                }]
+            })
+        );
+
+        let d = outbound::modify_issue("HT-21")
+            .comment_adf(&adf::Document::from_markdown("*done*"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap()["comment"]["content"][0]["content"][0]
+                ["marks"][0]["type"],
+            "em"
+        );
+    }
+
+    #[test]
+    fn adf_round_trip_test() {
+        let text = |text: &str| adf::Node::Paragraph {
+            content: vec![adf::Node::Text {
+                text: text.to_string(),
+                marks: vec![],
+            }],
+        };
+
+        for s in [
+            "# not a heading",
+            "> not a quote",
+            "- not a list",
+            "+ not a list",
+            "* not a list",
+            "1. not a list",
+            "2) not a list",
+            "---",
+            "\\",
+            "a\\",
+            "-5 and 3.14 stay as is",
+        ] {
+            let d = adf::Document::new(vec![text(s)]);
+            assert_eq!(adf::Document::from_markdown(&d.to_markdown()), d, "{}", s);
+        }
+
+        let d = adf::Document::new(vec![adf::Node::Paragraph {
+            content: vec![
+                adf::Node::Text {
+                    text: "a~".to_string(),
+                    marks: vec![adf::Mark::Strike],
+                },
+                adf::Node::HardBreak,
+                adf::Node::Text {
+                    text: "docs".to_string(),
+                    marks: vec![adf::Mark::Link {
+                        attrs: adf::LinkAttrs {
+                            href: "https://example.com/a (b)<c>".to_string(),
+                            title: None,
+                        },
+                    }],
+                },
+            ],
+        }]);
+        assert_eq!(
+            d.to_markdown(),
+            "~~a\\~~~\\\n[docs](<https://example.com/a (b)\\<c\\>>)"
+        );
+        assert_eq!(adf::Document::from_markdown(&d.to_markdown()), d);
+
+        // The Markdown may change, but parses into the same document again.
+        for (markdown, rendered) in [
+            ("\\", "\\\\"),
+            ("- ", "-"),
+            ("1. ", "1."),
+            ("~~a~~~", "~~a\\~~~"),
+        ] {
+            let d = adf::Document::from_markdown(markdown);
+            assert_eq!(d.to_markdown(), rendered);
+            assert_eq!(adf::Document::from_markdown(rendered), d);
+        }
+    }
+
+    #[test]
+    fn adf_malformed_node_test() {
+        let d: adf::Document = serde_json::from_value(json!({
+            "version": 1,
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [
+                    { "type": "text", "text": "see ", "marks": [{ "type": "link" }] },
+                    { "type": "inlineCard", "attrs": { "data": {} } },
+                    { "type": "heading", "content": [] },
+                    { "type": "mediaSingle", "content": [] }
+                ]
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(d.to_markdown(), "see");
+        match &d.content[0] {
+            adf::Node::Paragraph { content } => assert_eq!(content[2], adf::Node::Unknown),
+            node => panic!("not a paragraph: {:?}", node),
+        }
+    }
+
     #[test]
     fn comment_created_test() {
         let s = include_str!("jira_comment_created.json").to_string();
@@ -25,4 +411,332 @@ mod jira_tests {
         let s = include_str!("jira_comment_updated.json").to_string();
         println!("{:#?}", inbound(s).unwrap().get_comment().unwrap());
     }
+
+    #[test]
+    fn worklog_test() {
+        let s = include_str!("jira_worklog_created.json").to_string();
+        let d = inbound(s).unwrap();
+        let worklog = d.get_worklog().unwrap();
+
+        assert_eq!(d.webhook_event, JiraEvent::WorklogCreated);
+        assert_eq!(worklog.time_spent_seconds, 12600);
+        assert_eq!(
+            worklog.comment.as_ref().unwrap().to_plain_text(),
+            "Investigated the login issue"
+        );
+
+        let mut v: Value = serde_json::from_str(include_str!("jira_issue_updated.json")).unwrap();
+        let fields = &mut v["issue"]["fields"];
+        fields["timespent"] = json!(12600);
+        fields["timetracking"] = json!({
+            "originalEstimate": "1d",
+            "remainingEstimate": "4h 30m",
+            "timeSpent": "3h 30m",
+            "originalEstimateSeconds": 28800,
+            "remainingEstimateSeconds": 16200,
+            "timeSpentSeconds": 12600
+        });
+        let d = inbound(v.to_string()).unwrap();
+        let fields = &d.get_issue().unwrap().fields;
+
+        assert_eq!(fields.timespent, Some(12600));
+        assert_eq!(
+            fields
+                .timetracking
+                .as_ref()
+                .unwrap()
+                .remaining_estimate_seconds,
+            Some(16200)
+        );
+    }
+
+    #[test]
+    fn sprint_version_test() {
+        let s = include_str!("jira_sprint_started.json").to_string();
+        let d = inbound(s).unwrap();
+        let sprint = d.get_sprint().unwrap();
+
+        assert_eq!(d.webhook_event, JiraEvent::SprintStarted);
+        assert_eq!(sprint.state, "active");
+        assert_eq!(sprint.origin_board_id, Some(1));
+        assert!(sprint.board_id.is_none());
+        assert_eq!(sprint.goal.as_deref(), Some("Ship the login page"));
+        assert!(sprint.complete_date.is_none());
+        assert!(d.get_version().is_err());
+
+        let mut v: Value = serde_json::from_str(include_str!("jira_sprint_started.json")).unwrap();
+        v["sprint"]["boardId"] = json!(2);
+        let d = inbound(v.to_string()).unwrap();
+        let sprint = d.get_sprint().unwrap();
+
+        assert_eq!(sprint.board_id, Some(2));
+        assert_eq!(sprint.origin_board_id, Some(1));
+
+        let s = include_str!("jira_version_released.json").to_string();
+        let d = inbound(s).unwrap();
+        let version = d.get_version().unwrap();
+
+        assert_eq!(d.webhook_event, JiraEvent::VersionReleased);
+        assert_eq!(version.name, "v1.0");
+        assert!(version.released);
+        assert!(!version.archived);
+        assert_eq!(version.release_date.as_deref(), Some("2022-10-20"));
+        assert_eq!(version.project_id, Some(10000));
+    }
+
+    #[test]
+    fn issue_link_test() {
+        let s = include_str!("jira_issuelink_created.json").to_string();
+        let d = inbound(s).unwrap();
+        let link = d.get_issue_link().unwrap();
+
+        assert_eq!(d.webhook_event, JiraEvent::IssueLinkCreated);
+        assert_eq!(link.source_issue_id, 10021);
+        assert_eq!(link.destination_issue_id, 10022);
+        assert_eq!(link.issue_link_type.name, "Blocks");
+        assert_eq!(link.issue_link_type.outward_name, "blocks");
+        assert_eq!(link.issue_link_type.inward_name, "is blocked by");
+        assert!(d.get_issue().is_err());
+        assert_eq!(
+            inbound(include_str!("jira_sprint_started.json").to_string())
+                .unwrap()
+                .get_issue_link()
+                .unwrap_err(),
+            "Missing issueLink"
+        );
+
+        let title = "Fix login page".to_string();
+        let d =
+            outbound::create_remote_link("HT-21", "https://github.com/ho-229/test/pull/2", title)
+                .icon("https://github.com/favicon.ico", String::from("GitHub"))
+                .global_id("github-pr-ho-229/test#2")
+                .build()
+                .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "issue_key": "HT-21",
+                "url": "https://github.com/ho-229/test/pull/2",
+                "title": "Fix login page",
+                "icon_url": "https://github.com/favicon.ico",
+                "icon_title": "GitHub",
+                "global_id": "github-pr-ho-229/test#2"
+            })
+        );
+    }
+
+    #[test]
+    fn duration_test() {
+        let t = WorkingTime::default();
+
+        assert_eq!(
+            t.parse_duration("1w 2d 3h 30m").unwrap(),
+            7 * 8 * 3600 + 12600
+        );
+        assert_eq!(t.parse_duration("1h30m").unwrap(), 5400);
+        assert_eq!(t.parse_duration("1.5h").unwrap(), 5400);
+        assert!(t.parse_duration("").is_err());
+        assert!(t.parse_duration("30").is_err());
+        assert!(t.parse_duration("2x").is_err());
+        assert!(t.parse_duration(".h").is_err());
+        assert!(t.parse_duration("1.2.3h").is_err());
+        assert_eq!(t.parse_duration(".5m").unwrap(), 30);
+        assert!(t.parse_duration("99999999999999999999w").is_err());
+        assert!(t.parse_duration("9999999999999999w").is_err());
+        assert!(t
+            .parse_duration(&format!("{}m {}m", u64::MAX / 60, u64::MAX / 60))
+            .is_err());
+        assert_eq!(t.format_duration(7 * 8 * 3600 + 12600), "1w 2d 3h 30m");
+        assert_eq!(t.format_duration(0), "0m");
+
+        let t = WorkingTime {
+            hours_per_day: 6,
+            days_per_week: 5,
+        };
+        assert_eq!(t.parse_duration("2d").unwrap(), 12 * 3600);
+        assert_eq!(t.format_duration(8 * 3600), "1d 2h");
+
+        let d = outbound::log_work("HT-21", t.parse_duration("3h 30m").unwrap())
+            .started("2022-10-11T09:00:00.000+0800")
+            .comment("Investigated the login issue")
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "issue_key": "HT-21",
+                "time_spent_seconds": 12600,
+                "started": "2022-10-11T09:00:00.000+0800",
+                "comment": "Investigated the login issue"
+            })
+        );
+        assert!(outbound::log_work("HT-21", 0).build().is_err());
+    }
+
+    #[test]
+    fn jql_test() {
+        use jql::*;
+
+        let q = Query::new()
+            .filter(field("project").eq("HT"))
+            .filter(
+                field("status")
+                    .eq("Done")
+                    .or(field("labels").is_in(vec!["hot\"fix", "back\\slash"])),
+            )
+            .filter(!field("Story Points").is_empty())
+            .filter(field("cf[10020]").is_in(vec![open_sprints()]))
+            .filter(field("priority").ge(3))
+            .filter(field("summary").contains("login page"))
+            .order_by("created", Order::Desc)
+            .order_by("Story Points", Order::Asc);
+        assert_eq!(
+            q.to_string(),
+            concat!(
+                r#"project = "HT" AND (status = "Done" OR labels IN ("hot\"fix", "back\\slash")) "#,
+                r#"AND NOT "Story Points" IS EMPTY AND cf[10020] IN (openSprints()) "#,
+                r#"AND priority >= 3 AND summary ~ "login page" "#,
+                r#"ORDER BY created DESC, "Story Points" ASC"#
+            )
+        );
+
+        let q = Query::from(
+            Clause::from(
+                field("status")
+                    .changed()
+                    .from("In Progress")
+                    .to("Done")
+                    .by(current_user())
+                    .after("-1w"),
+            )
+            .and(
+                field("assignee")
+                    .was_not("ho229")
+                    .during("2022-10-01", "2022-10-31"),
+            )
+            .and(
+                field("fixVersion")
+                    .was_in(vec![released_versions("HT")])
+                    .before(now()),
+            ),
+        );
+        assert_eq!(
+            q.to_string(),
+            concat!(
+                r#"status CHANGED FROM "In Progress" TO "Done" BY currentUser() AFTER "-1w" "#,
+                r#"AND assignee WAS NOT "ho229" DURING ("2022-10-01", "2022-10-31") "#,
+                r#"AND fixVersion WAS IN (releasedVersions("HT")) BEFORE now()"#
+            )
+        );
+
+        let q = Query::from(Clause::from(field("status").was_in(vec!["Done", "Closed"]))).filter(
+            field("status")
+                .was_not_in(vec!["In Review"])
+                .by(current_user()),
+        );
+        assert_eq!(
+            q.to_string(),
+            concat!(
+                r#"status WAS IN ("Done", "Closed") "#,
+                r#"AND status WAS NOT IN ("In Review") BY currentUser()"#
+            )
+        );
+
+        let name = "membersOf".to_string();
+        let q = Query::from(field("assignee").is_in(vec![function(name, vec!["devs"])]))
+            .filter(field("status").was("Done").during("2022-10-01", now()));
+        assert_eq!(
+            q.to_string(),
+            r#"assignee IN (membersOf("devs")) AND status WAS "Done" DURING ("2022-10-01", now())"#
+        );
+
+        // Empty lists are not rejected, Jira fails on the rendered `IN ()`.
+        assert_eq!(
+            field("labels").is_in(Vec::<&str>::new()).to_string(),
+            "labels IN ()"
+        );
+        assert_eq!(
+            field("status").was_not_in(Vec::<&str>::new()).to_string(),
+            "status WAS NOT IN ()"
+        );
+
+        let q = Query::new().order_by("rank", Order::Asc);
+        assert_eq!(q.to_string(), "ORDER BY rank ASC");
+
+        let q = Query::new().filter(field("assignee").eq(current_user()));
+        let d = outbound::search(&q)
+            .max_results(50)
+            .fields(vec!["summary", "status"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "jql": "assignee = currentUser()",
+                "max_results": 50,
+                "fields": ["summary", "status"]
+            })
+        );
+    }
+
+    #[test]
+    fn outbound_test() {
+        let d = outbound::modify_issue("HT-21")
+            .comment("Deployed to staging")
+            .assignee("5b10ac8d82e05b22cc7d4ef5")
+            .add_labels(vec!["backend"])
+            .add_labels(vec!["triaged"])
+            .remove_labels(vec!["urgent"])
+            .priority("High")
+            .components(vec!["API"])
+            .fix_versions(vec!["v1.0"])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "issue_key": "HT-21",
+                "comment": "Deployed to staging",
+                "assignee": "5b10ac8d82e05b22cc7d4ef5",
+                "labels_add": ["backend", "triaged"],
+                "labels_remove": ["urgent"],
+                "priority": "High",
+                "components": ["API"],
+                "fix_versions": ["v1.0"]
+            })
+        );
+
+        let d = outbound::modify_issue("HT-21").unassign().build().unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({ "issue_key": "HT-21", "assignee": null })
+        );
+
+        assert!(outbound::modify_issue("HT-21")
+            .add_labels(vec!["needs review"])
+            .build()
+            .is_err());
+
+        let parent_key = "HT-20".to_string();
+        let d = outbound::create_subtask(&parent_key, "Write docs")
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({ "parent_key": "HT-20", "summary": "Write docs" })
+        );
+
+        let d = outbound::link_issues("Blocks", parent_key.replace("20", "21"), "HT-22")
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "link_type": "Blocks",
+                "inward_issue_key": "HT-21",
+                "outward_issue_key": "HT-22"
+            })
+        );
+    }
 }
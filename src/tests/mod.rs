@@ -1,4 +1,5 @@
 mod github_tests;
+mod github_jira_tests;
 mod gmail_tests;
 mod jira_tests;
 mod discord_tests;
@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod telegram_tests {
+    use serde_json::{json, Value};
+
     use crate::telegram::*;
 
     #[test]
@@ -15,4 +17,152 @@ mod telegram_tests {
 
         println!("{:#?}", inbound(s).unwrap());
     }
+
+    #[test]
+    fn update() {
+        let mut v: Value = serde_json::from_str(include_str!("telegram_message.json")).unwrap();
+        v["update_id"] = json!(123456789);
+        let d = inbound_update(v.to_string()).unwrap();
+
+        assert_eq!(d.update_id, Some(123456789));
+        assert_eq!(d.as_message().unwrap().text, "/ping");
+
+        // An unsupported field sorting before the update type is ignored.
+        v["business_connection"] = json!({ "id": "42" });
+        let d = inbound_update(v.to_string()).unwrap();
+        assert_eq!(d.as_message().unwrap().text, "/ping");
+
+        let s = include_str!("telegram_message.json").to_string();
+        assert!(inbound_update(s).unwrap().update_id.is_none());
+
+        let d = inbound_update(
+            json!({
+                "update_id": 123456790,
+                "poll": { "id": "5432", "question": "Lunch?" }
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        match &d.kind {
+            InboundData::Unknown { key, value } => {
+                assert_eq!(key, "poll");
+                assert_eq!(value["question"], "Lunch?");
+            }
+            kind => panic!("unexpected update {:?}", kind),
+        }
+        assert!(d.as_message().is_err());
+
+        let d = inbound_update(json!({ "update_id": 1 }).to_string()).unwrap();
+        match &d.kind {
+            InboundData::Unknown { key, value } => assert!(key.is_empty() && value.is_null()),
+            kind => panic!("unexpected update {:?}", kind),
+        }
+        assert!(inbound(json!({ "message": { "text": "hi" } }).to_string()).is_err());
+    }
+
+    #[test]
+    fn callback_query() {
+        let s = include_str!("telegram_callback_query.json").to_string();
+        let d = inbound(s).unwrap();
+        let query = d.as_callback_query().unwrap();
+
+        assert_eq!(query.data.as_deref(), Some("deploy:yes"));
+        assert_eq!(query.from.id, 1688457911);
+        assert_eq!(query.message.as_ref().unwrap().message_id, 56);
+
+        let d = outbound::answer_callback_query(&query.id)
+            .text("Deploying")
+            .show_alert()
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "callback_query_id": "7251967219351412374",
+                "text": "Deploying",
+                "show_alert": true
+            })
+        );
+    }
+
+    #[test]
+    fn keyboards() {
+        use outbound::*;
+
+        let d = outbound::message(1688457911, "Deploy to production?")
+            .reply_markup(
+                InlineKeyboard::new()
+                    .row(vec![
+                        InlineKeyboardButton::callback("Yes", "deploy:yes"),
+                        InlineKeyboardButton::callback("No", "deploy:no"),
+                    ])
+                    .row(vec![
+                        InlineKeyboardButton::url("Logs", "https://flows.network"),
+                        InlineKeyboardButton::switch_inline("Share", "deploy"),
+                    ]),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap()["reply_markup"],
+            json!({
+                "inline_keyboard": [
+                    [
+                        { "text": "Yes", "callback_data": "deploy:yes" },
+                        { "text": "No", "callback_data": "deploy:no" }
+                    ],
+                    [
+                        { "text": "Logs", "url": "https://flows.network" },
+                        { "text": "Share", "switch_inline_query": "deploy" }
+                    ]
+                ]
+            })
+        );
+
+        assert!(outbound::message(1688457911, "Too long")
+            .reply_markup(
+                InlineKeyboard::new().row(vec![InlineKeyboardButton::callback(
+                    "Go",
+                    "x".repeat(MAX_CALLBACK_DATA_LEN + 1)
+                )])
+            )
+            .build()
+            .is_err());
+
+        let d = outbound::message(1688457911, "Share your location")
+            .reply_markup(
+                ReplyKeyboard::new()
+                    .row(vec![KeyboardButton::new("Here").request_location()])
+                    .row(vec!["Cancel"])
+                    .resize()
+                    .one_time(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap()["reply_markup"],
+            json!({
+                "keyboard": [
+                    [{ "text": "Here", "request_location": true }],
+                    [{ "text": "Cancel" }]
+                ],
+                "resize_keyboard": true,
+                "one_time_keyboard": true
+            })
+        );
+
+        let d = outbound::message(1688457911, "Thanks")
+            .reply_markup(KeyboardRemove::new())
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&d).unwrap(),
+            json!({
+                "chat_id": "1688457911",
+                "text": "Thanks",
+                "reply_markup": { "remove_keyboard": true }
+            })
+        );
+    }
 }